@@ -79,6 +79,7 @@
 //! [requires]: https://github.com/servo/rust-smallvec/issues/160
 
 /// We need to allocate elements which haven't been consumed by the PeekMore iterator.
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 /// Import std only when running doc tests without errors. Std will not be included outside of
@@ -101,11 +102,45 @@ extern crate std;
 #[global_allocator]
 static A: std::alloc::System = std::alloc::System;
 
+#[cfg(feature = "alloc")]
+mod peek_builder;
+#[cfg(feature = "alloc")]
+mod peek_bytes;
+#[cfg(feature = "alloc")]
+mod peek_chars;
+#[cfg(feature = "alloc")]
+mod peek_decision;
+#[cfg(feature = "heapless")]
+mod peek_heapless;
+#[cfg(feature = "alloc")]
 mod peek_iterator;
+#[cfg(feature = "alloc")]
+mod peek_lazy;
+#[cfg(feature = "alloc")]
+mod peek_merge;
+#[cfg(feature = "alloc")]
+mod peek_results;
+#[cfg(feature = "alloc")]
+mod peek_single;
 mod peekerror;
 mod peekmore;
 
 // Public exports
+#[cfg(feature = "alloc")]
+pub use peek_builder::{GrowthStrategy, PeekMoreBuilder};
+#[cfg(feature = "alloc")]
+pub use peek_decision::PeekDecision;
+#[cfg(feature = "heapless")]
+pub use peek_heapless::HeaplessPeekMoreIterator;
+#[cfg(feature = "alloc")]
 pub use peek_iterator::PeekMoreIterator;
+#[cfg(feature = "alloc")]
+pub use peek_lazy::Peeks;
+#[cfg(feature = "alloc")]
+pub use peek_merge::merge_by;
+#[cfg(feature = "alloc")]
+pub use peek_single::SinglePeekable;
 pub use peekerror::PeekMoreError;
 pub use peekmore::PeekMore;
+#[cfg(feature = "alloc")]
+pub use peekmore::peekmore_from_fn;
@@ -0,0 +1,89 @@
+/// Use a `Vec` to queue iterator elements
+use alloc::vec::Vec;
+
+use crate::peek_iterator::PeekMoreIterator;
+
+/// Determines how the queue of a [`PeekMoreIterator`] grows once it is full.
+///
+/// [`PeekMoreIterator`]: struct.PeekMoreIterator.html
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum GrowthStrategy {
+    /// Grow the queue like a `Vec` grows by default, i.e. by (at least) doubling its capacity.
+    #[default]
+    Amortized,
+    /// Grow the queue by a fixed amount of elements at a time. This trades more reallocations for
+    /// less unused capacity. A step of `0` is treated as a step of `1`.
+    Linear(usize),
+}
+
+/// Builder which configures a [`PeekMoreIterator`] before it is created.
+///
+/// Created with [`PeekMore::peekmore_builder`].
+///
+/// ```rust
+/// use obsessive_peek::{GrowthStrategy, PeekMore};
+///
+/// let mut iter = (0..)
+///     .peekmore_builder()
+///     .capacity(16)
+///     .limit(64)
+///     .growth(GrowthStrategy::Linear(16))
+///     .build();
+///
+/// assert_eq!(iter.peek_nth(63), Some(&63));
+/// assert_eq!(iter.peek_nth(64), None);
+/// ```
+///
+/// [`PeekMoreIterator`]: struct.PeekMoreIterator.html
+/// [`PeekMore::peekmore_builder`]: trait.PeekMore.html#method.peekmore_builder
+#[derive(Clone, Debug)]
+pub struct PeekMoreBuilder<I: Iterator> {
+    iterator: I,
+    capacity: usize,
+    limit: Option<usize>,
+    growth: GrowthStrategy,
+}
+
+impl<I: Iterator> PeekMoreBuilder<I> {
+    pub(crate) fn new(iterator: I) -> Self {
+        PeekMoreBuilder {
+            iterator,
+            capacity: 0,
+            limit: None,
+            growth: GrowthStrategy::default(),
+        }
+    }
+
+    /// Allocate room for (at least) `n` elements in the queue up front.
+    pub fn capacity(mut self, n: usize) -> Self {
+        self.capacity = n;
+        self
+    }
+
+    /// Buffer at most `n` elements ahead, as [`PeekMore::peekmore_limited`] does.
+    ///
+    /// [`PeekMore::peekmore_limited`]: trait.PeekMore.html#method.peekmore_limited
+    pub fn limit(mut self, n: usize) -> Self {
+        self.limit = Some(n);
+        self
+    }
+
+    /// Set the strategy which determines how the queue grows once it is full.
+    pub fn growth(mut self, strategy: GrowthStrategy) -> Self {
+        self.growth = strategy;
+        self
+    }
+
+    /// Create the configured multi-peek iterator.
+    pub fn build(self) -> PeekMoreIterator<I> {
+        PeekMoreIterator {
+            iterator: self.iterator,
+            queue: Vec::with_capacity(self.capacity),
+            cursor: 0usize,
+            limit: self.limit,
+            growth: self.growth,
+            back_queue: Vec::new(),
+            consumed: 0usize,
+        }
+    }
+}
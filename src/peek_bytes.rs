@@ -0,0 +1,24 @@
+/// Use a `Vec` to collect peeked bytes
+use alloc::vec::Vec;
+
+use crate::peek_iterator::PeekMoreIterator;
+
+/// Convenience methods for iterators over bytes.
+impl<I: Iterator<Item = u8>> PeekMoreIterator<I> {
+    /// Peek at the next `n` unconsumed bytes, and return them as a `Vec<u8>`.
+    ///
+    /// If the underlying iterator runs out of bytes, the returned vector holds fewer than `n`
+    /// bytes. No bytes are consumed and the cursor is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = b"\x89PNG\r\n".iter().copied().peekmore();
+    ///
+    /// assert_eq!(iter.peek_bytes(4), b"\x89PNG");
+    /// assert_eq!(iter.next(), Some(0x89));
+    /// ```
+    pub fn peek_bytes(&mut self, n: usize) -> Vec<u8> {
+        self.peek_amount(n).iter().map_while(|&b| b).collect()
+    }
+}
@@ -0,0 +1,190 @@
+/// Use a `String` to collect peeked chars
+use alloc::string::String;
+
+use crate::peek_iterator::PeekMoreIterator;
+
+/// Convenience methods for iterators over `char`s, such as [`core::str::Chars`].
+///
+/// [`core::str::Chars`]: https://doc.rust-lang.org/core/str/struct.Chars.html
+impl<I: Iterator<Item = char>> PeekMoreIterator<I> {
+    /// Returns whether the next unconsumed chars are equal to the chars of `s`.
+    ///
+    /// The comparison is made char by char, so multi-byte chars are matched naturally. No elements
+    /// are consumed and the cursor is not moved. An empty `s` always matches.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "let x".chars().peekmore();
+    ///
+    /// assert!(iter.peek_str_len("let"));
+    /// assert!(!iter.peek_str_len("fn"));
+    /// ```
+    pub fn peek_str_len(&mut self, s: &str) -> bool {
+        let len = s.chars().count();
+        let peeked = self.peek_amount(len);
+
+        // the view is shorter than `len` if it was cut off by a lookahead limit
+        peeked.len() == len
+            && peeked
+                .iter()
+                .zip(s.chars())
+                .all(|(peeked, expected)| *peeked == Some(expected))
+    }
+
+    /// Peek at the next `n` unconsumed chars, and return them as a `String`.
+    ///
+    /// If the underlying iterator runs out of chars, the returned string holds fewer than `n`
+    /// chars. No chars are consumed and the cursor is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "hello".chars().peekmore();
+    ///
+    /// assert_eq!(iter.peek_string(3), "hel");
+    /// assert_eq!(iter.next(), Some('h'));
+    /// ```
+    pub fn peek_string(&mut self, n: usize) -> String {
+        self.peek_amount(n).iter().map_while(|&c| c).collect()
+    }
+
+    /// Consume the next `n` chars, and return them as a `String`.
+    ///
+    /// This is the consuming counterpart of [`peek_string`]. If the underlying iterator runs out of
+    /// chars, the returned string holds fewer than `n` chars. The cursor moves along with the consumed
+    /// chars, as it does for [`next()`].
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "hello".chars().peekmore();
+    ///
+    /// assert_eq!(iter.consume_string(3), "hel");
+    /// assert_eq!(iter.next(), Some('l'));
+    /// ```
+    ///
+    /// [`peek_string`]: struct.PeekMoreIterator.html#method.peek_string
+    /// [`next()`]: struct.PeekMoreIterator.html#impl-Iterator
+    pub fn consume_string(&mut self, n: usize) -> String {
+        self.by_ref().take(n).collect()
+    }
+
+    /// Returns the amount of leading unconsumed chars which form an ASCII decimal number.
+    ///
+    /// A number consists of an optional `-` sign, one or more ASCII digits, and optionally a `.`
+    /// followed by one or more ASCII digits. If the chars don't start with a number, `0` is
+    /// returned. No chars are consumed and the cursor is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "-12.5e".chars().peekmore();
+    ///
+    /// assert_eq!(iter.peek_number_len(), 5);
+    /// assert_eq!(iter.next(), Some('-'));
+    /// ```
+    pub fn peek_number_len(&mut self) -> usize {
+        let sign = usize::from(self.peek_nth(0) == Some(&'-'));
+
+        let integer = self.ascii_digits_at(sign);
+        if integer == 0 {
+            return 0;
+        }
+
+        let len = sign + integer;
+        if self.peek_nth(len) != Some(&'.') {
+            return len;
+        }
+
+        match self.ascii_digits_at(len + 1) {
+            0 => len,
+            fraction => len + 1 + fraction,
+        }
+    }
+
+    /// Consume the leading unconsumed chars which form an ASCII decimal number, as recognized by
+    /// [`peek_number_len`], and return them as a `String`.
+    ///
+    /// If the chars don't start with a number, nothing is consumed and `None` is returned. The
+    /// cursor moves along with the consumed chars, as it does for [`next()`].
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "42 rest".chars().peekmore();
+    ///
+    /// assert_eq!(iter.consume_number(), Some("42".to_string()));
+    /// assert_eq!(iter.next(), Some(' '));
+    /// ```
+    ///
+    /// [`peek_number_len`]: struct.PeekMoreIterator.html#method.peek_number_len
+    /// [`next()`]: struct.PeekMoreIterator.html#impl-Iterator
+    pub fn consume_number(&mut self) -> Option<String> {
+        match self.peek_number_len() {
+            0 => None,
+            len => Some(self.consume_string(len)),
+        }
+    }
+
+    /// Returns the amount of leading unconsumed chars which are whitespace, according to
+    /// [`char::is_whitespace`].
+    ///
+    /// No chars are consumed and the cursor is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = " \t\nx".chars().peekmore();
+    ///
+    /// assert_eq!(iter.peek_whitespace_len(), 3);
+    /// ```
+    ///
+    /// [`char::is_whitespace`]: https://doc.rust-lang.org/core/primitive.char.html#method.is_whitespace
+    pub fn peek_whitespace_len(&mut self) -> usize {
+        let mut len = 0;
+
+        while self.peek_nth(len).is_some_and(|c| c.is_whitespace()) {
+            len += 1;
+        }
+
+        len
+    }
+
+    /// Consume the leading unconsumed chars which are whitespace, according to
+    /// [`char::is_whitespace`], and return how many were skipped.
+    ///
+    /// The cursor moves along with the consumed chars, as it does for [`next()`].
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "   x".chars().peekmore();
+    ///
+    /// assert_eq!(iter.skip_whitespace(), 3);
+    /// assert_eq!(iter.next(), Some('x'));
+    /// ```
+    ///
+    /// [`char::is_whitespace`]: https://doc.rust-lang.org/core/primitive.char.html#method.is_whitespace
+    /// [`next()`]: struct.PeekMoreIterator.html#impl-Iterator
+    pub fn skip_whitespace(&mut self) -> usize {
+        let mut skipped = 0;
+
+        while self.next_if(|c| c.is_whitespace()).is_some() {
+            skipped += 1;
+        }
+
+        skipped
+    }
+
+    /// Returns the amount of consecutive ASCII digits starting at queue index `start`.
+    fn ascii_digits_at(&mut self, start: usize) -> usize {
+        let mut len = 0;
+
+        while self.peek_nth(start + len).is_some_and(char::is_ascii_digit) {
+            len += 1;
+        }
+
+        len
+    }
+}
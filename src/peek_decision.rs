@@ -0,0 +1,11 @@
+/// The decision made by the closure passed to [`PeekMoreIterator::peek_then`], on whether the
+/// peeked element should be consumed. Both variants hold the value returned by `peek_then`.
+///
+/// [`PeekMoreIterator::peek_then`]: struct.PeekMoreIterator.html#method.peek_then
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PeekDecision<R> {
+    /// Consume the peeked element, and return the held value.
+    Consume(R),
+    /// Keep the peeked element unconsumed, and return the held value.
+    Keep(R),
+}
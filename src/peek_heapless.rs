@@ -0,0 +1,122 @@
+use core::iter::FusedIterator;
+
+use crate::peekerror::PeekMoreError;
+
+/// A multi-peek iterator which stores its queue in a fixed capacity [`heapless::Vec`] instead of
+/// an allocated `Vec`.
+///
+/// At most `N` elements can be buffered. Peeking at an element which would require the queue to
+/// grow beyond `N` elements returns a [`PeekMoreError::CapacityExceeded`] instead of allocating.
+///
+/// This iterator can be created with [`PeekMore::peekmore_heapless`], and is only available when
+/// the `heapless` feature is enabled.
+///
+/// [`heapless::Vec`]: https://docs.rs/heapless/latest/heapless/vec/type.Vec.html
+/// [`PeekMoreError::CapacityExceeded`]: enum.PeekMoreError.html#variant.CapacityExceeded
+/// [`PeekMore::peekmore_heapless`]: trait.PeekMore.html#tymethod.peekmore_heapless
+#[derive(Clone, Debug)]
+pub struct HeaplessPeekMoreIterator<I: Iterator, const N: usize> {
+    /// The underlying iterator. Consumption of this inner iterator does not represent consumption of the
+    /// `HeaplessPeekMoreIterator`.
+    pub iterator: I,
+
+    /// The queue represents the items of our iterator which have not been consumed, but can be peeked
+    /// at without consuming them. It can hold at most `N` elements.
+    pub queue: heapless::Vec<Option<I::Item>, N>,
+
+    /// The cursor points to the element we are currently peeking at.
+    ///
+    /// The cursor will point to the first unconsumed element if the value is `0`, the second if it is
+    /// `1`, and so forth.
+    pub cursor: usize,
+}
+
+impl<I: Iterator, const N: usize> HeaplessPeekMoreIterator<I, N> {
+    /// Get a reference to the element where the cursor currently points to.
+    ///
+    /// If the element is not buffered yet and the queue is full, a
+    /// [`PeekMoreError::CapacityExceeded`] is returned.
+    ///
+    /// [`PeekMoreError::CapacityExceeded`]: enum.PeekMoreError.html#variant.CapacityExceeded
+    #[inline]
+    pub fn peek(&mut self) -> Result<Option<&I::Item>, PeekMoreError> {
+        self.peek_nth(self.cursor)
+    }
+
+    /// Advance the cursor to the next element and return a reference to that value.
+    #[inline]
+    pub fn peek_next(&mut self) -> Result<Option<&I::Item>, PeekMoreError> {
+        self.advance_cursor();
+        self.peek()
+    }
+
+    /// Peek at the nth element without moving the cursor.
+    ///
+    /// If `n` is not smaller than the capacity `N`, a [`PeekMoreError::CapacityExceeded`] is
+    /// returned.
+    ///
+    /// [`PeekMoreError::CapacityExceeded`]: enum.PeekMoreError.html#variant.CapacityExceeded
+    #[inline]
+    pub fn peek_nth(&mut self, n: usize) -> Result<Option<&I::Item>, PeekMoreError> {
+        self.fill_queue(n)?;
+        Ok(self.queue.get(n).and_then(|v| v.as_ref()))
+    }
+
+    /// Advance the cursor to the next peekable element.
+    ///
+    /// A mutable reference to the iterator is returned, which allows the operation to be chained.
+    #[inline]
+    pub fn advance_cursor(&mut self) -> &mut HeaplessPeekMoreIterator<I, N> {
+        self.cursor = self.cursor.saturating_add(1);
+        self
+    }
+
+    /// Reset the position of the cursor.
+    #[inline]
+    pub fn reset_cursor(&mut self) {
+        self.cursor = 0;
+    }
+
+    /// Return the current cursor position.
+    #[inline]
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Fills the queue up to (including) the given index, or returns an error if the queue
+    /// can't hold that many elements.
+    fn fill_queue(&mut self, required_elements: usize) -> Result<(), PeekMoreError> {
+        if required_elements >= N {
+            return Err(PeekMoreError::CapacityExceeded);
+        }
+
+        for _ in self.queue.len()..=required_elements {
+            let item = self.iterator.next();
+            // can't fail, since the queue is smaller than `required_elements < N`
+            let _ = self.queue.push(item);
+        }
+
+        Ok(())
+    }
+}
+
+impl<I: Iterator, const N: usize> Iterator for HeaplessPeekMoreIterator<I, N> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let res = if self.queue.is_empty() {
+            self.iterator.next()
+        } else {
+            self.queue.remove(0)
+        };
+
+        self.cursor = self.cursor.saturating_sub(1);
+
+        res
+    }
+}
+
+/// Uses [`FusedIterator`] default implementation.
+///
+/// [`FusedIterator`]: https://doc.rust-lang.org/core/iter/trait.FusedIterator.html
+impl<I: FusedIterator, const N: usize> FusedIterator for HeaplessPeekMoreIterator<I, N> {}
@@ -1,9 +1,26 @@
-use core::iter::FusedIterator;
-
+use core::fmt::Debug;
+use core::iter::{Chain, FusedIterator, Sum, Take};
+use core::ops::ControlFlow;
+
+/// Use a `Cow` to hand out possibly owned peeked elements
+use alloc::borrow::Cow;
+/// Use a `BTreeMap` to count peeked elements
+use alloc::collections::BTreeMap;
+/// Use a `String` to summarize the state of the iterator
+use alloc::format;
+use alloc::string::String;
 /// Use a `Vec` to queue iterator elements
-use alloc::vec::Vec;
+use alloc::vec::{self, Vec};
 
+use crate::peek_builder::GrowthStrategy;
+use crate::peek_decision::PeekDecision;
+use crate::peek_lazy::Peeks;
+use crate::peek_single::SinglePeekable;
 use crate::peekerror::PeekMoreError;
+use crate::peekmore::PeekMore;
+
+/// The first element of a peeked view, together with the rest of the view.
+type SplitFirst<'a, T> = (&'a T, &'a [Option<T>]);
 
 /// This iterator makes it possible to peek multiple times without consuming a value.
 /// In reality the underlying iterator will be consumed, but the values will be stored in a queue.
@@ -31,6 +48,37 @@ pub struct PeekMoreIterator<I: Iterator> {
     ///
     /// [`core::iter::Peekable::peek`]: https://doc.rust-lang.org/core/iter/struct.Peekable.html#method.peek
     pub cursor: usize,
+
+    /// The maximum amount of elements the queue may hold, if any.
+    ///
+    /// Set with [`PeekMore::peekmore_limited`]. Once the queue holds this many elements, no further
+    /// elements are buffered until elements are consumed.
+    ///
+    /// [`PeekMore::peekmore_limited`]: trait.PeekMore.html#method.peekmore_limited
+    pub(crate) limit: Option<usize>,
+
+    /// Determines how the queue grows once it is full.
+    ///
+    /// Set with [`PeekMoreBuilder::growth`].
+    ///
+    /// [`PeekMoreBuilder::growth`]: struct.PeekMoreBuilder.html#method.growth
+    pub(crate) growth: GrowthStrategy,
+
+    /// The elements which were taken from the back of the underlying iterator by
+    /// [`peek_nth_back`], starting with the last element.
+    ///
+    /// Once the underlying iterator runs out of elements at the front, the elements are taken from
+    /// this queue instead, starting with its end.
+    ///
+    /// [`peek_nth_back`]: struct.PeekMoreIterator.html#method.peek_nth_back
+    pub(crate) back_queue: Vec<I::Item>,
+
+    /// The amount of elements which have been consumed so far.
+    ///
+    /// See [`consumed`].
+    ///
+    /// [`consumed`]: struct.PeekMoreIterator.html#method.consumed
+    pub(crate) consumed: usize,
 }
 
 impl<I: Iterator> PeekMoreIterator<I> {
@@ -236,6 +284,84 @@ impl<I: Iterator> PeekMoreIterator<I> {
         self.queue.get(self.cursor).and_then(|v| v.as_ref())
     }
 
+    /// Get a [`Cow::Borrowed`] of the element where the cursor currently points to, or `None` if
+    /// no such element exists.
+    ///
+    /// This is the same element as returned by [`peek`], in a shape which lets downstream code
+    /// decide whether it needs to own the element, with [`Cow::into_owned`].
+    ///
+    /// ```rust
+    /// use std::borrow::Cow;
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_cow(), Some(Cow::Borrowed(&1)));
+    /// ```
+    ///
+    /// [`Cow::Borrowed`]: https://doc.rust-lang.org/alloc/borrow/enum.Cow.html#variant.Borrowed
+    /// [`peek`]: struct.PeekMoreIterator.html#method.peek
+    /// [`Cow::into_owned`]: https://doc.rust-lang.org/alloc/borrow/enum.Cow.html#method.into_owned
+    #[inline]
+    pub fn peek_cow(&mut self) -> Option<Cow<'_, I::Item>>
+    where
+        I::Item: Clone,
+    {
+        self.peek().map(Cow::Borrowed)
+    }
+
+    /// Returns whether the element the cursor points to is equal to any of the elements of `set`.
+    ///
+    /// Returns `false` if no such element exists. No elements are consumed and the cursor is not
+    /// moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "a1".chars().peekmore();
+    ///
+    /// assert!(iter.peek_any_of(&['a', 'b', 'c']));
+    /// assert!(!iter.advance_cursor().peek_any_of(&['a', 'b', 'c']));
+    /// ```
+    pub fn peek_any_of<T>(&mut self, set: &[T]) -> bool
+    where
+        I::Item: PartialEq<T>,
+    {
+        self.peek()
+            .is_some_and(|element| set.iter().any(|member| element == member))
+    }
+
+    /// Returns whether the cursor points beyond the last element of the iterator, i.e. whether
+    /// [`peek`] returns `None` because the underlying iterator ran out of elements.
+    ///
+    /// This is useful when the elements are `Option`s themselves, where [`peek`] returning
+    /// `Some(&None)` for a `None` element is easily confused with the end of the iterator. If a
+    /// lookahead limit prevents buffering the element at the cursor, `false` is returned, since
+    /// the end of the iterator hasn't been reached.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [Some(1), None].into_iter().peekmore();
+    ///
+    /// iter.advance_cursor();
+    /// assert_eq!(iter.peek(), Some(&None));
+    /// assert!(!iter.is_exhausted());
+    ///
+    /// iter.advance_cursor();
+    /// assert!(iter.is_exhausted());
+    /// ```
+    ///
+    /// [`peek`]: struct.PeekMoreIterator.html#method.peek
+    pub fn is_exhausted(&mut self) -> bool {
+        self.fill_queue(self.cursor);
+
+        match self.queue.get(self.cursor) {
+            Some(element) => element.is_none(),
+            None => self.is_queue_exhausted(),
+        }
+    }
+
     /// Peeks at the first unconsumed element, regardless of where the cursor currently is.
     #[inline]
     pub fn peek_first(&mut self) -> Option<&I::Item> {
@@ -272,6 +398,35 @@ impl<I: Iterator> PeekMoreIterator<I> {
         this.peek()
     }
 
+    /// Move the cursor at most `n` steps forward, without moving it beyond the last element of
+    /// the iterator, and peek at the element the cursor then points to.
+    ///
+    /// Unlike [`peek_forward`], which can move the cursor arbitrarily far beyond the end of the
+    /// iterator, the cursor stops at the last element. If the cursor already points beyond the
+    /// last element, it is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_forward_clamped(10), Some(&&3));
+    /// assert_eq!(iter.cursor(), 2);
+    /// ```
+    ///
+    /// [`peek_forward`]: struct.PeekMoreIterator.html#method.peek_forward
+    pub fn peek_forward_clamped(&mut self, n: usize) -> Option<&I::Item> {
+        for _ in 0..n {
+            if self.peek_nth(self.cursor.saturating_add(1)).is_none() {
+                break;
+            }
+
+            self.increment_cursor();
+        }
+
+        self.peek()
+    }
+
     /// Move the cursor `n` steps backward and peek at the element the cursor then points to.
     ///
     /// If there aren't `n` elements prior to the element the cursor currently points at, a
@@ -303,12 +458,173 @@ impl<I: Iterator> PeekMoreIterator<I> {
     }
 
     /// Peek at the nth element without moving the cursor.
+    ///
+    /// The queue is buffered up to index `n`, unless the underlying iterator is exhausted before
+    /// that, in which case buffering stops at its end and `None` is returned.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_nth(1_000_000), None);
+    /// assert_eq!(iter.buffered().len(), 4);
+    /// ```
     #[inline]
     pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
         self.fill_queue(n);
         self.queue.get(n).and_then(|v| v.as_ref())
     }
 
+    /// Peek at the nth element without moving the cursor, and stop buffering as soon as the
+    /// underlying iterator is exhausted.
+    ///
+    /// This is a thin wrapper around [`peek_nth`], which stops buffering at the end of the
+    /// underlying iterator as well: once it is exhausted, a single `None` is buffered to mark the
+    /// end, and `None` is returned for any `n` at or beyond it, without growing the queue further.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_nth_lazy(1_000_000), None);
+    /// assert_eq!(iter.buffered().len(), 4);
+    /// ```
+    ///
+    /// [`peek_nth`]: struct.PeekMoreIterator.html#method.peek_nth
+    #[inline]
+    pub fn peek_nth_lazy(&mut self, n: usize) -> Option<&I::Item> {
+        self.peek_nth(n)
+    }
+
+    /// Peek at the nth element without moving the cursor, or return a
+    /// [`PeekMoreError::LookaheadExceeded`] if doing so would require buffering more elements
+    /// than the lookahead limit allows.
+    ///
+    /// Without a lookahead limit, this method always succeeds and is equivalent to [`peek_nth`].
+    ///
+    /// ```rust
+    /// use obsessive_peek::{PeekMore, PeekMoreError};
+    ///
+    /// let mut iter = (0..).peekmore_limited(3);
+    ///
+    /// assert_eq!(iter.try_peek_nth(2), Ok(Some(&2)));
+    /// assert_eq!(iter.try_peek_nth(3), Err(PeekMoreError::LookaheadExceeded));
+    /// ```
+    ///
+    /// [`PeekMoreError::LookaheadExceeded`]: enum.PeekMoreError.html#variant.LookaheadExceeded
+    /// [`peek_nth`]: struct.PeekMoreIterator.html#method.peek_nth
+    #[inline]
+    pub fn try_peek_nth(&mut self, n: usize) -> Result<Option<&I::Item>, PeekMoreError> {
+        self.check_lookahead(n)?;
+
+        Ok(self.peek_nth(n))
+    }
+
+    /// Returns a [`PeekMoreError::LookaheadExceeded`] if the element at queue index `index` can't
+    /// be buffered without exceeding the lookahead limit.
+    ///
+    /// [`PeekMoreError::LookaheadExceeded`]: enum.PeekMoreError.html#variant.LookaheadExceeded
+    #[inline]
+    fn check_lookahead(&self, index: usize) -> Result<(), PeekMoreError> {
+        match self.limit {
+            Some(max) if index >= max => Err(PeekMoreError::LookaheadExceeded),
+            _ => Ok(()),
+        }
+    }
+
+    /// Return the lookahead limit of this iterator, if it has one.
+    ///
+    /// See [`PeekMore::peekmore_limited`].
+    ///
+    /// [`PeekMore::peekmore_limited`]: trait.PeekMore.html#method.peekmore_limited
+    #[inline]
+    pub fn lookahead_limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    /// Peek at the element the cursor points to, and at the element after it.
+    ///
+    /// This is convenient when the current and the next element are needed together, since both
+    /// references can be held at the same time. The cursor is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_two(), (Some(&&1), Some(&&2)));
+    ///
+    /// iter.advance_cursor_by(2);
+    /// assert_eq!(iter.peek_two(), (Some(&&3), None));
+    /// ```
+    #[inline]
+    pub fn peek_two(&mut self) -> (Option<&I::Item>, Option<&I::Item>) {
+        let cursor = self.cursor;
+        self.peek_both(cursor, cursor.saturating_add(1))
+    }
+
+    /// Peek at the nth element and the element after it, without moving the cursor.
+    ///
+    /// Like [`peek_nth`], `n` is counted from the first unconsumed element, regardless of the
+    /// position of the cursor.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3, 4];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_pair_at(2), (Some(&&3), Some(&&4)));
+    /// ```
+    ///
+    /// [`peek_nth`]: struct.PeekMoreIterator.html#method.peek_nth
+    #[inline]
+    pub fn peek_pair_at(&mut self, n: usize) -> (Option<&I::Item>, Option<&I::Item>) {
+        self.peek_both(n, n.saturating_add(1))
+    }
+
+    /// Peek at the element the cursor points to, and at the element `offset` positions after it.
+    ///
+    /// This is convenient for matching pairs of elements relative to the cursor, such as digraphs,
+    /// since both references can be held at the same time. With an `offset` of `1`, this is
+    /// equivalent to [`peek_two`]. The cursor is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3, 4];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// iter.advance_cursor();
+    /// assert_eq!(iter.peek_cursor_and_offset(2), (Some(&&2), Some(&&4)));
+    /// ```
+    ///
+    /// [`peek_two`]: struct.PeekMoreIterator.html#method.peek_two
+    #[inline]
+    pub fn peek_cursor_and_offset(
+        &mut self,
+        offset: usize,
+    ) -> (Option<&I::Item>, Option<&I::Item>) {
+        let cursor = self.cursor;
+        self.peek_both(cursor, cursor.saturating_add(offset))
+    }
+
+    /// Peek at the elements at queue indices `first` and `second`, where `first <= second`.
+    fn peek_both(&mut self, first: usize, second: usize) -> (Option<&I::Item>, Option<&I::Item>) {
+        self.fill_queue(second);
+
+        let queue = self.queue.as_slice();
+        (
+            queue.get(first).and_then(|v| v.as_ref()),
+            queue.get(second).and_then(|v| v.as_ref()),
+        )
+    }
+
     /// Advance the cursor to the next peekable element.
     ///
     /// This method does not advance the iterator itself. To advance the iterator, call [`next()`]
@@ -338,6 +654,101 @@ impl<I: Iterator> PeekMoreIterator<I> {
         }
     }
 
+    /// Advance the cursor `n` elements forward, or return a [`PeekMoreError::LookaheadExceeded`] if
+    /// the cursor would then point to an element beyond the lookahead limit. In case of an error, the
+    /// cursor stays at the position it pointed at prior to calling this method.
+    ///
+    /// Without a lookahead limit, this method always succeeds and is equivalent to
+    /// [`advance_cursor_by`]. In particular, the cursor may be moved beyond the last element of the
+    /// iterator; to prevent that, use [`try_advance_cursor`] instead.
+    ///
+    /// [`PeekMoreError::LookaheadExceeded`]: enum.PeekMoreError.html#variant.LookaheadExceeded
+    /// [`advance_cursor_by`]: struct.PeekMoreIterator.html#method.advance_cursor_by
+    /// [`try_advance_cursor`]: struct.PeekMoreIterator.html#method.try_advance_cursor
+    #[inline]
+    pub fn try_advance_cursor_by(
+        &mut self,
+        n: usize,
+    ) -> Result<&mut PeekMoreIterator<I>, PeekMoreError> {
+        self.check_lookahead(self.cursor.saturating_add(n))?;
+
+        Ok(self.advance_cursor_by(n))
+    }
+
+    /// Advance the cursor `n` elements forward, but only if there are at least `n` elements at or
+    /// beyond the element the cursor currently points to. Otherwise, a
+    /// [`PeekMoreError::CursorOutOfBounds`] is returned, and the cursor stays where it was.
+    ///
+    /// The queue is buffered up to the last element the cursor moves over. If that element can't be
+    /// buffered because of the lookahead limit, a [`PeekMoreError::LookaheadExceeded`] is returned
+    /// instead, as [`try_advance_cursor_by`] does. Unlike [`try_advance_cursor_by`], which only
+    /// checks the lookahead limit, this method never moves the cursor beyond the last element. To
+    /// find out how far the cursor can still be advanced, use [`cursor_distance_to_end`].
+    ///
+    /// ```rust
+    /// use obsessive_peek::{PeekMore, PeekMoreError};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.try_advance_cursor(5).err(), Some(PeekMoreError::CursorOutOfBounds));
+    /// assert_eq!(iter.cursor(), 0);
+    ///
+    /// assert_eq!(iter.try_advance_cursor(2).map(|iter| iter.cursor()), Ok(2));
+    /// ```
+    ///
+    /// [`PeekMoreError::CursorOutOfBounds`]: enum.PeekMoreError.html#variant.CursorOutOfBounds
+    /// [`PeekMoreError::LookaheadExceeded`]: enum.PeekMoreError.html#variant.LookaheadExceeded
+    /// [`try_advance_cursor_by`]: struct.PeekMoreIterator.html#method.try_advance_cursor_by
+    /// [`cursor_distance_to_end`]: struct.PeekMoreIterator.html#method.cursor_distance_to_end
+    pub fn try_advance_cursor(
+        &mut self,
+        n: usize,
+    ) -> Result<&mut PeekMoreIterator<I>, PeekMoreError> {
+        if n > 0 {
+            let last = self.cursor.saturating_add(n - 1);
+            self.check_lookahead(last)?;
+
+            if self.peek_nth(last).is_none() {
+                return Err(PeekMoreError::CursorOutOfBounds);
+            }
+        }
+
+        Ok(self.advance_cursor_by(n))
+    }
+
+    /// Advance the cursor `n` elements forward, and report how many elements of the iterator the
+    /// cursor moved over, and whether it ended up beyond the last element of the iterator.
+    ///
+    /// The queue is buffered up to the new position of the cursor. Like [`advance_cursor_by`],
+    /// the cursor is always moved by `n`; the returned count is smaller than `n` only if the
+    /// cursor ran off the end of the iterator. To refuse moving beyond the last element instead,
+    /// use [`try_advance_cursor`].
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.advance_cursor_by_checked(2), (2, false));
+    /// assert_eq!(iter.advance_cursor_by_checked(3), (1, true));
+    /// ```
+    ///
+    /// [`advance_cursor_by`]: struct.PeekMoreIterator.html#method.advance_cursor_by
+    /// [`try_advance_cursor`]: struct.PeekMoreIterator.html#method.try_advance_cursor
+    pub fn advance_cursor_by_checked(&mut self, n: usize) -> (usize, bool) {
+        let start = self.cursor;
+        self.advance_cursor_by(n);
+
+        let reached_end = self.is_exhausted();
+        let end = self.cursor.min(self.queue.len());
+        let moved = self
+            .queue
+            .get(start..end)
+            .map_or(0, |skipped| skipped.iter().filter(|v| v.is_some()).count());
+
+        (moved, reached_end)
+    }
+
     /// Advance the cursor `n` elements forward with optimization for large jumps.
     /// Uses divide and conquer strategy to ensure the queue has sufficient capacity.
     ///
@@ -397,14 +808,78 @@ impl<I: Iterator> PeekMoreIterator<I> {
         &mut self,
         predicate: P,
     ) -> &mut PeekMoreIterator<I> {
-        let view = self.peek();
+        // A loop instead of recursion, so long runs of matching elements can't overflow the stack.
+        while predicate(self.peek()) {
+            self.increment_cursor();
+        }
 
-        if predicate(view) {
+        self
+    }
+
+    /// Moves the cursor forward until the predicate is no longer `true`, like [`advance_cursor_while`],
+    /// but also passes the position of the cursor to the predicate.
+    ///
+    /// The position passed to `predicate` is the cursor position of the element being tested,
+    /// which makes it possible to cap the amount of elements the cursor moves over.
+    ///
+    /// This does not advance the iterator itself. To advance the iterator, call [`next()`] instead.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = (1..100).peekmore();
+    ///
+    /// iter.advance_cursor_while_indexed(|i, _| i < 3);
+    /// assert_eq!(iter.cursor(), 3);
+    /// assert_eq!(iter.peek(), Some(&4));
+    /// ```
+    ///
+    /// [`advance_cursor_while`]: struct.PeekMoreIterator.html#method.advance_cursor_while
+    /// [`next()`]: struct.PeekMoreIterator.html#impl-Iterator
+    pub fn advance_cursor_while_indexed<P: Fn(usize, Option<&I::Item>) -> bool>(
+        &mut self,
+        predicate: P,
+    ) -> &mut PeekMoreIterator<I> {
+        while predicate(self.cursor, self.peek()) {
             self.increment_cursor();
-            self.advance_cursor_while(predicate)
-        } else {
-            self
         }
+
+        self
+    }
+
+    /// Advance the cursor for as long as it points to an element which is equal to the element
+    /// the cursor pointed to initially, i.e. to the first element which differs from it.
+    ///
+    /// Each element is compared to the initial element, rather than to the element before it. If
+    /// the cursor doesn't point to an element, it is not moved. If the run of equal elements lasts
+    /// until the end of the iterator, the cursor stops just beyond the last element.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [3, 3, 3, 4, 3].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.advance_cursor_until_change().peek(), Some(&4));
+    /// assert_eq!(iter.cursor(), 3);
+    /// ```
+    pub fn advance_cursor_until_change(&mut self) -> &mut PeekMoreIterator<I>
+    where
+        I::Item: PartialEq,
+    {
+        let start = self.cursor;
+
+        loop {
+            self.fill_queue(self.cursor);
+
+            match (self.queue.get(start), self.queue.get(self.cursor)) {
+                (Some(Some(initial)), Some(Some(current))) if initial == current => {
+                    self.increment_cursor()
+                }
+                _ => break,
+            }
+        }
+
+        self
     }
 
     /// Move the cursor to the previous peekable element.
@@ -430,10 +905,10 @@ impl<I: Iterator> PeekMoreIterator<I> {
     /// it pointed at prior to calling this method.
     ///
     /// If you want to reset the cursor to the first unconsumed element even if there aren't `n`
-    /// unconsumed elements before the cursor position, the [`move_backward_or_reset`] method can be
-    /// used.
+    /// unconsumed elements before the cursor position, the [`move_cursor_back_or_reset`] method can
+    /// be used.
     ///
-    /// [`move_backward_or_reset`]: struct.PeekMoreIterator.html#method.move_backward_or_reset
+    /// [`move_cursor_back_or_reset`]: struct.PeekMoreIterator.html#method.move_cursor_back_or_reset
     #[inline]
     pub fn move_cursor_back_by(
         &mut self,
@@ -461,6 +936,33 @@ impl<I: Iterator> PeekMoreIterator<I> {
         self
     }
 
+    /// Move the cursor up to `n` elements backward, stopping at the first unconsumed element, and
+    /// return the amount of elements the cursor actually moved.
+    ///
+    /// Unlike [`move_cursor_back_by`], this method never fails, and unlike
+    /// [`move_cursor_back_or_reset`], it reports how far the cursor moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3, 4];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// iter.advance_cursor_by(2);
+    /// assert_eq!(iter.move_cursor_back_saturating(5), 2);
+    /// assert_eq!(iter.cursor(), 0);
+    /// ```
+    ///
+    /// [`move_cursor_back_by`]: struct.PeekMoreIterator.html#method.move_cursor_back_by
+    /// [`move_cursor_back_or_reset`]: struct.PeekMoreIterator.html#method.move_cursor_back_or_reset
+    #[inline]
+    pub fn move_cursor_back_saturating(&mut self, n: usize) -> usize {
+        let moved = n.min(self.cursor);
+        self.cursor -= moved;
+
+        moved
+    }
+
     /// Move the cursor to the n-th element of the queue.
     #[inline]
     pub fn move_nth(&mut self, n: usize) -> &mut PeekMoreIterator<I> {
@@ -468,15 +970,129 @@ impl<I: Iterator> PeekMoreIterator<I> {
         self
     }
 
-    /// Deprecated: use [`reset_cursor`] instead.
+    /// Move the cursor to the n-th element of the queue, but only if that element exists.
+    /// Otherwise, a [`PeekMoreError::CursorOutOfBounds`] is returned, and the cursor stays where it
+    /// was.
     ///
-    /// [`reset_cursor`]: struct.PeekMoreIterator.html#method.reset_cursor
-    #[deprecated]
+    /// The queue is buffered up to the n-th element. If it can't be buffered because of the
+    /// lookahead limit, a [`PeekMoreError::LookaheadExceeded`] is returned instead. Use
+    /// [`move_nth`] to move the cursor without any checks.
+    ///
+    /// ```rust
+    /// use obsessive_peek::{PeekMore, PeekMoreError};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.move_nth_checked(2).map(|iter| iter.cursor()), Ok(2));
+    /// assert_eq!(iter.move_nth_checked(3).err(), Some(PeekMoreError::CursorOutOfBounds));
+    /// assert_eq!(iter.cursor(), 2);
+    /// ```
+    ///
+    /// [`PeekMoreError::CursorOutOfBounds`]: enum.PeekMoreError.html#variant.CursorOutOfBounds
+    /// [`PeekMoreError::LookaheadExceeded`]: enum.PeekMoreError.html#variant.LookaheadExceeded
+    /// [`move_nth`]: struct.PeekMoreIterator.html#method.move_nth
+    pub fn move_nth_checked(
+        &mut self,
+        n: usize,
+    ) -> Result<&mut PeekMoreIterator<I>, PeekMoreError> {
+        self.check_lookahead(n)?;
+
+        if self.peek_nth(n).is_none() {
+            return Err(PeekMoreError::CursorOutOfBounds);
+        }
+
+        Ok(self.move_nth(n))
+    }
+
+    /// Move the cursor to the element at position `pos`, and buffer the queue up to and including
+    /// that element, so a subsequent [`peek`] doesn't have to take elements from the underlying
+    /// iterator.
+    ///
+    /// Unlike [`move_nth`], which only sets the cursor, this method fills the queue.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.advance_cursor_to(2).peek(), Some(&3));
+    /// ```
+    ///
+    /// [`peek`]: struct.PeekMoreIterator.html#method.peek
+    /// [`move_nth`]: struct.PeekMoreIterator.html#method.move_nth
+    pub fn advance_cursor_to(&mut self, pos: usize) -> &mut PeekMoreIterator<I> {
+        self.fill_queue(pos);
+        self.cursor = pos;
+        self
+    }
+
+    /// Deprecated: use [`reset_cursor`] instead.
+    ///
+    /// [`reset_cursor`]: struct.PeekMoreIterator.html#method.reset_cursor
+    #[deprecated(note = "use `reset_cursor` instead")]
     #[inline]
     pub fn reset_view(&mut self) {
         self.reset_cursor()
     }
 
+    /// Deprecated: use [`advance_cursor`] instead.
+    ///
+    /// This alias exists to ease migrating from older `peekmore` versions.
+    ///
+    /// [`advance_cursor`]: struct.PeekMoreIterator.html#method.advance_cursor
+    #[deprecated(note = "use `advance_cursor` instead")]
+    #[inline]
+    pub fn advance_view(&mut self) -> &mut PeekMoreIterator<I> {
+        self.advance_cursor()
+    }
+
+    /// Deprecated: use [`advance_cursor_by`] instead.
+    ///
+    /// This alias exists to ease migrating from older `peekmore` versions.
+    ///
+    /// [`advance_cursor_by`]: struct.PeekMoreIterator.html#method.advance_cursor_by
+    #[deprecated(note = "use `advance_cursor_by` instead")]
+    #[inline]
+    pub fn advance_view_by(&mut self, n: usize) -> &mut PeekMoreIterator<I> {
+        self.advance_cursor_by(n)
+    }
+
+    /// Deprecated: use [`move_cursor_back`] instead.
+    ///
+    /// This alias exists to ease migrating from older `peekmore` versions.
+    ///
+    /// [`move_cursor_back`]: struct.PeekMoreIterator.html#method.move_cursor_back
+    #[deprecated(note = "use `move_cursor_back` instead")]
+    #[inline]
+    pub fn move_backward(&mut self) -> Result<&mut PeekMoreIterator<I>, PeekMoreError> {
+        self.move_cursor_back()
+    }
+
+    /// Deprecated: use [`move_cursor_back_by`] instead.
+    ///
+    /// This alias exists to ease migrating from older `peekmore` versions.
+    ///
+    /// [`move_cursor_back_by`]: struct.PeekMoreIterator.html#method.move_cursor_back_by
+    #[deprecated(note = "use `move_cursor_back_by` instead")]
+    #[inline]
+    pub fn move_backward_by(
+        &mut self,
+        n: usize,
+    ) -> Result<&mut PeekMoreIterator<I>, PeekMoreError> {
+        self.move_cursor_back_by(n)
+    }
+
+    /// Deprecated: use [`move_cursor_back_or_reset`] instead.
+    ///
+    /// This alias exists to ease migrating from older `peekmore` versions.
+    ///
+    /// [`move_cursor_back_or_reset`]: struct.PeekMoreIterator.html#method.move_cursor_back_or_reset
+    #[deprecated(note = "use `move_cursor_back_or_reset` instead")]
+    #[inline]
+    pub fn move_backward_or_reset(&mut self, n: usize) -> &mut PeekMoreIterator<I> {
+        self.move_cursor_back_or_reset(n)
+    }
+
     /// Reset the position of the cursor.
     ///
     /// If [`peek`] is called just after a reset, it will return a reference to the first element.
@@ -487,6 +1103,32 @@ impl<I: Iterator> PeekMoreIterator<I> {
         self.cursor = 0;
     }
 
+    /// Reset the position of the cursor, and shrink the capacity of the queue to fit the elements
+    /// which are currently buffered.
+    ///
+    /// This is useful after peeking far ahead, when the grown queue capacity is no longer needed:
+    /// [`reset_cursor`] alone keeps that capacity around. A typical case is rewinding after a
+    /// failed speculative parse. The buffered elements themselves are kept, so they can be peeked
+    /// at again.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = (0..100).peekmore();
+    /// iter.advance_cursor_to(3);
+    ///
+    /// iter.reset_and_shrink();
+    /// assert_eq!(iter.cursor(), 0);
+    /// assert_eq!(iter.peek_nth(3), Some(&3));
+    /// ```
+    ///
+    /// [`reset_cursor`]: struct.PeekMoreIterator.html#method.reset_cursor
+    #[inline]
+    pub fn reset_and_shrink(&mut self) {
+        self.reset_cursor();
+        self.queue.shrink_to(self.queue.len());
+    }
+
     /// Return the current cursor position.
     /// This is intended for use by code that more finely controls where the iterator resets to.
     #[inline]
@@ -494,244 +1136,1852 @@ impl<I: Iterator> PeekMoreIterator<I> {
         self.cursor
     }
 
-    /// Fills the queue up to (including) the cursor.
+    /// Return the amount of elements which have been consumed so far, by [`next()`] or any other
+    /// method which consumes elements.
+    ///
+    /// Together with the cursor, this gives the position of an element in the input: the element
+    /// the cursor points to is the element at index [`absolute_cursor`] of the input. Elements
+    /// which are inserted into, replaced in or removed from the queue with methods such as
+    /// [`insert_peeked`] or [`remove_peeked`] are not accounted for.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "abc".chars().peekmore();
+    /// iter.next();
+    ///
+    /// assert_eq!(iter.consumed(), 1);
+    /// ```
+    ///
+    /// [`next()`]: struct.PeekMoreIterator.html#impl-Iterator
+    /// [`absolute_cursor`]: struct.PeekMoreIterator.html#method.absolute_cursor
+    /// [`insert_peeked`]: struct.PeekMoreIterator.html#method.insert_peeked
+    /// [`remove_peeked`]: struct.PeekMoreIterator.html#method.remove_peeked
     #[inline]
-    fn fill_queue(&mut self, required_elements: usize) {
-        let stored_elements = self.queue.len();
-
-        if stored_elements <= required_elements {
-            // Use divide and conquer for large batches
-            let elements_needed = required_elements - stored_elements + 1;
-
-            if elements_needed > 1000 {
-                self.fill_queue_divide_conquer(required_elements);
-            } else {
-                for _ in stored_elements..=required_elements {
-                    self.push_next_to_queue()
-                }
-            }
-        }
+    pub fn consumed(&self) -> usize {
+        self.consumed
     }
 
-    /// Fill queue using divide and conquer strategy for large batches.
-    /// This method reduces the overhead of repeated function calls for large numbers of elements.
-    fn fill_queue_divide_conquer(&mut self, required_elements: usize) {
-        let current_len = self.queue.len();
-        let remaining = required_elements - current_len + 1;
+    /// Return the position of the element the cursor points to, relative to the start of the input
+    /// rather than to the first unconsumed element, i.e. the amount of [`consumed`] elements plus
+    /// the cursor.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "abcd".chars().peekmore();
+    /// iter.next();
+    /// iter.advance_cursor();
+    ///
+    /// assert_eq!(iter.peek(), Some(&'c'));
+    /// assert_eq!(iter.absolute_cursor(), 2);
+    /// ```
+    ///
+    /// [`consumed`]: struct.PeekMoreIterator.html#method.consumed
+    #[inline]
+    pub fn absolute_cursor(&self) -> usize {
+        self.consumed.saturating_add(self.cursor)
+    }
 
-        // For very large batches, use chunked processing
-        const CHUNK_SIZE: usize = 500;
+    /// Move the cursor to the element at position `abs` of the input, i.e. such that the
+    /// [`absolute_cursor`] becomes `abs`.
+    ///
+    /// If the element at that position has already been consumed, a
+    /// [`PeekMoreError::ElementHasBeenConsumed`] is returned instead, and the cursor stays where it
+    /// was. Like [`move_nth`], no elements are buffered.
+    ///
+    /// ```rust
+    /// use obsessive_peek::{PeekMore, PeekMoreError};
+    ///
+    /// let mut iter = "abcd".chars().peekmore();
+    /// iter.next();
+    ///
+    /// assert_eq!(iter.seek_absolute(2).map(|iter| iter.peek().copied()), Ok(Some('c')));
+    /// assert_eq!(iter.seek_absolute(0).err(), Some(PeekMoreError::ElementHasBeenConsumed));
+    /// ```
+    ///
+    /// [`absolute_cursor`]: struct.PeekMoreIterator.html#method.absolute_cursor
+    /// [`PeekMoreError::ElementHasBeenConsumed`]: enum.PeekMoreError.html#variant.ElementHasBeenConsumed
+    /// [`move_nth`]: struct.PeekMoreIterator.html#method.move_nth
+    pub fn seek_absolute(&mut self, abs: usize) -> Result<&mut PeekMoreIterator<I>, PeekMoreError> {
+        let n = abs
+            .checked_sub(self.consumed)
+            .ok_or(PeekMoreError::ElementHasBeenConsumed)?;
 
-        if remaining > CHUNK_SIZE {
-            let chunks = remaining / CHUNK_SIZE;
-            let remainder = remaining % CHUNK_SIZE;
+        Ok(self.move_nth(n))
+    }
 
-            // Process full chunks
-            for _ in 0..chunks {
-                for _ in 0..CHUNK_SIZE {
-                    self.push_next_to_queue();
-                }
-            }
+    /// Buffer the queue up to and including queue index `index`, without peeking or moving the
+    /// cursor.
+    ///
+    /// This makes it possible to buffer a known amount of elements up front, e.g. before inspecting
+    /// them with [`buffered`]. Buffering stops early once the underlying iterator is exhausted (in
+    /// which case a `None` marks its end), or once the lookahead limit is reached.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = (0..2).peekmore();
+    ///
+    /// iter.fill_to(5);
+    /// assert_eq!(iter.buffered(), &[Some(0), Some(1), None]);
+    /// ```
+    ///
+    /// [`buffered`]: struct.PeekMoreIterator.html#method.buffered
+    #[inline]
+    pub fn fill_to(&mut self, index: usize) -> &mut PeekMoreIterator<I> {
+        self.fill_queue(index);
+        self
+    }
 
-            // Process remaining elements
-            for _ in 0..remainder {
-                self.push_next_to_queue();
-            }
-        } else {
-            // For smaller batches, use the original approach
-            for _ in current_len..=required_elements {
-                self.push_next_to_queue();
-            }
-        }
+    /// Return a view of the elements which are currently buffered in the queue.
+    ///
+    /// Unlike [`peek_amount`], this method doesn't buffer any additional elements; it only shows
+    /// what has been taken from the underlying iterator, but not yet consumed.
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    #[inline]
+    pub fn buffered(&self) -> &[Option<I::Item>] {
+        self.queue.as_slice()
     }
 
-    /// Consume the underlying iterator and push an element to the queue.
+    /// Return the amount of entries which are currently buffered in the queue, including the `None`s
+    /// at the end of the queue.
+    ///
+    /// Once the end of the underlying iterator is reached, a `None` is buffered to mark it. Views
+    /// such as [`peek_range`] and [`peek_amount`] may pad the queue with more `None`s, which are
+    /// counted as well. Like [`buffered`], this method doesn't buffer any additional elements.
+    ///
+    /// [`peek_range`]: struct.PeekMoreIterator.html#method.peek_range
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    /// [`buffered`]: struct.PeekMoreIterator.html#method.buffered
     #[inline]
-    fn push_next_to_queue(&mut self) {
-        let item = self.iterator.next();
-        self.queue.push(item);
+    pub fn buffered_len(&self) -> usize {
+        self.queue.len()
     }
 
-    /// Increment the cursor which points to the current peekable item.
-    /// Note: if the cursor is [core::usize::MAX], it will not increment any further.
+    /// Return the amount of elements which are currently buffered in the queue, excluding the `None`s
+    /// at the end of the queue, which mark the end of the underlying iterator.
     ///
-    /// [core::usize::MAX]: https://doc.rust-lang.org/core/usize/constant.MAX.html
+    /// Like [`buffered`], this method doesn't buffer any additional elements.
+    ///
+    /// [`buffered`]: struct.PeekMoreIterator.html#method.buffered
     #[inline]
-    fn increment_cursor(&mut self) {
-        // do not overflow
-        self.cursor = self.cursor.saturating_add(1);
+    pub fn buffered_some_len(&self) -> usize {
+        self.queue.iter().take_while(|v| v.is_some()).count()
     }
 
-    /// Decrement the cursor which points to the current peekable item.
-    /// Note: if the cursor is [core::usize::MIN], it will not decrement any further.
+    /// Return a reference to the last element which is currently buffered in the queue, or `None`
+    /// if no elements are buffered.
     ///
-    /// [core::usize::MIN]: https://doc.rust-lang.org/core/usize/constant.MIN.html
+    /// No additional elements are taken from the underlying iterator, so this is not necessarily the
+    /// last element of the iterator.
     #[inline]
-    fn decrement_cursor(&mut self) {
-        if self.cursor > usize::MIN {
-            self.cursor -= 1;
-        }
+    pub fn last_buffered(&self) -> Option<&I::Item> {
+        self.queue.iter().rev().find_map(Option::as_ref)
     }
 
-    /// Remove all elements from the start of the iterator until reaching the same
-    /// position as the cursor by calling `Iterator::next()`.
+    /// Create a copy of this iterator, if the underlying iterator has been buffered completely.
     ///
-    /// After calling this method, `iter.peek() == iter.next().as_ref()`.
+    /// Unlike `clone`, this doesn't require the underlying iterator to implement `Clone`: the copy
+    /// is backed by the buffered elements instead. The copy keeps the cursor position, the amount
+    /// of consumed elements and the lookahead limit, and can be consumed independently. Returns
+    /// `None` if the end of the underlying iterator hasn't been buffered yet.
     ///
-    ///```rust
+    /// ```rust
     /// use obsessive_peek::PeekMore;
     ///
-    /// let iterable = [1, 2, 3, 4];
-    /// let mut iter = iterable.iter().peekmore();
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    /// assert!(iter.try_clone_buffered().is_none());
     ///
-    /// iter.advance_cursor_by(2);
-    /// assert_eq!(iter.peek(), Some(&&3));
-    /// assert_eq!(iter.next(), Some(&1));
-    /// iter.truncate_iterator_to_cursor();
-    /// assert_eq!(iter.peek(), Some(&&3));
-    /// assert_eq!(iter.next(), Some(&3));
-    ///```
-    pub fn truncate_iterator_to_cursor(&mut self) {
-        if self.cursor < self.queue.len() {
-            self.queue.drain(0..self.cursor);
-        } else {
-            // if the cursor is greater than the queue length,
-            // we want to remove the overflow from the iterator
-            for _ in 0..self.cursor.saturating_sub(self.queue.len()) {
-                let _ = self.iterator.next();
-            }
-            self.queue.clear();
+    /// iter.peek_nth(3);
+    /// let copy = iter.try_clone_buffered().unwrap();
+    /// assert_eq!(copy.collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    pub fn try_clone_buffered(&self) -> Option<PeekMoreIterator<vec::IntoIter<I::Item>>>
+    where
+        I::Item: Clone,
+    {
+        if !self.is_queue_exhausted() {
+            return None;
         }
 
-        self.cursor = 0;
+        let elements: Vec<I::Item> = self.queue.iter().flatten().cloned().collect();
+
+        let mut copy = elements.into_iter().peekmore();
+        copy.cursor = self.cursor;
+        copy.limit = self.limit;
+        copy.growth = self.growth;
+        copy.consumed = self.consumed;
+
+        Some(copy)
     }
 
-    /// Returns a view into the next `start` (inclusive) to `end` (exclusive) elements.
+    /// Return a summary of the state of the multi-peek iterator, consisting of the cursor position,
+    /// the length of the queue and the buffered elements.
     ///
-    /// **Note:** `start` and `end` represent indices and start at `0`. These indices always start
-    /// at the beginning of the queue (the unconsumed iterator) and don't take the position of the cursor
-    /// into account.
+    /// Unlike the derived `Debug` implementation, the underlying iterator is left out, which keeps
+    /// the summary short and stable. No additional elements are buffered.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    /// iter.peek_nth(1);
+    ///
+    /// assert_eq!(iter.debug_state(), "cursor: 0, queue length: 2, buffered: [Some(1), Some(2)]");
+    /// ```
+    pub fn debug_state(&self) -> String
+    where
+        I::Item: Debug,
+    {
+        format!(
+            "cursor: {}, queue length: {}, buffered: {:?}",
+            self.cursor,
+            self.queue.len(),
+            self.queue
+        )
+    }
+
+    /// Check the internal invariants of the multi-peek iterator.
+    ///
+    /// The invariants are that no `Some` element is queued after a `None` element, since the end
+    /// of the underlying iterator is terminal, that the queue isn't padded with `None`s beyond the
+    /// lookahead limit (see [`PeekMore::peekmore_limited`]), which only inserted elements may
+    /// exceed, and that neither the amount of [`consumed`] and buffered elements, nor the
+    /// [`absolute_cursor`] position overflow. Since the
+    /// `queue` and `cursor` fields are public, and the buffer can be edited, this helps to catch a
+    /// corrupted state early.
+    ///
+    /// The checks are only made in debug builds, as with [`debug_assert!`]. They're also made
+    /// after the queue is filled or truncated, and after an element is consumed with `next`.
     ///
     /// # Panics
     ///
-    /// **Panics** if `start > end`, in which case the range would be negative.
+    /// **Panics** in debug builds if an invariant doesn't hold.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2].into_iter().peekmore();
+    /// iter.peek_nth(5);
     ///
+    /// iter.debug_assert_invariants();
     /// ```
+    ///
+    /// [`PeekMore::peekmore_limited`]: trait.PeekMore.html#method.peekmore_limited
+    /// [`consumed`]: struct.PeekMoreIterator.html#method.consumed
+    /// [`absolute_cursor`]: struct.PeekMoreIterator.html#method.absolute_cursor
+    /// [`debug_assert!`]: https://doc.rust-lang.org/core/macro.debug_assert.html
+    #[inline]
+    pub fn debug_assert_invariants(&self) {
+        self.debug_assert_invariants_from(0);
+    }
+
+    /// Check the internal invariants like [`debug_assert_invariants`], but only look for `Some`
+    /// elements after a `None` element from queue index `start` onwards, so operations which only
+    /// touch the tail of the queue don't pay for a scan of the whole queue.
+    ///
+    /// [`debug_assert_invariants`]: struct.PeekMoreIterator.html#method.debug_assert_invariants
+    #[inline]
+    fn debug_assert_invariants_from(&self, start: usize) {
+        debug_assert!(
+            self.queue
+                .get(start..)
+                .unwrap_or(&[])
+                .iter()
+                .skip_while(|element| element.is_some())
+                .all(Option::is_none),
+            "the queue holds an element after the end of the underlying iterator"
+        );
+        debug_assert!(
+            self.limit.is_none_or(|max| {
+                self.queue
+                    .get(max..)
+                    .is_none_or(|beyond| beyond.iter().rev().skip(1).all(Option::is_some))
+            }),
+            "the queue is padded beyond the lookahead limit"
+        );
+        debug_assert!(
+            self.consumed.checked_add(self.queue.len()).is_some(),
+            "the amount of consumed and buffered elements overflows"
+        );
+        debug_assert!(
+            self.consumed.checked_add(self.cursor).is_some(),
+            "the absolute position of the cursor overflows"
+        );
+    }
+
+    /// Returns the amount of elements at or beyond the cursor which have not been consumed yet.
+    ///
+    /// To find the end, the remaining elements of the underlying iterator are buffered. This has
+    /// a cost linear in the amount of remaining elements (both in time and memory), and will never
+    /// return for an infinite iterator.
+    ///
+    /// **Note:** With a lookahead limit (see [`PeekMore::peekmore_limited`]), buffering silently
+    /// stops at the limit, so the distance to the limit is returned if the end lies beyond it. Use
+    /// [`try_peek_last`] to find out whether the end can be reached.
+    ///
+    /// ```rust
     /// use obsessive_peek::PeekMore;
     ///
     /// let iterable = [1, 2, 3, 4];
     /// let mut iter = iterable.iter().peekmore();
     ///
-    /// match iter.peek_range(1, 3) {
-    ///     [Some(2), Some(p)] => println!("Yay! we found number {} after number 2", p),
-    ///     _ => println!("Oh noes!"),
-    /// }
+    /// iter.advance_cursor();
+    /// assert_eq!(iter.cursor_distance_to_end(), 3);
     /// ```
-    // implementation choice:
-    // why not `core::ops::RangeBound<T>`? it adds unnecessary complexity since we would need to define what
-    // unbounded bounds mean (e.g. for end whether it would be the end of the queue or the unconsumed iterator
-    // elements until None or that it won't be allowed, or some other definition), we would need to map
-    // the range Inclusive and Exclusive and Unbound-ed elements to usize, and we would need to verify
-    // that T would be an unsigned integer. Using RangeBound would not be all negative though since we
-    // could then use the standard Rust range syntax options such as 0..4 or 0..=3, which clearly
-    // tell a user what kind of bounds are used (inclusive, exclusive, etc.)
-    // For now however, for the reason of not adding unnecessary complexity, I've decided
-    // that the simplicity of concrete start and end types is the better choice.
-    pub fn peek_range(&mut self, start: usize, end: usize) -> &[Option<I::Item>] {
-        assert!(
-            start <= end,
-            "range of the peeked view [start, end] should be positive (i.e. start <= end)"
-        );
+    ///
+    /// [`PeekMore::peekmore_limited`]: trait.PeekMore.html#method.peekmore_limited
+    /// [`try_peek_last`]: struct.PeekMoreIterator.html#method.try_peek_last
+    pub fn cursor_distance_to_end(&mut self) -> usize {
+        self.fill_queue_to_end();
 
-        // For large ranges, use divide and conquer optimization
-        let range_size = end - start;
-        if range_size > 2000 {
-            self.peek_range_optimized(start, end)
-        } else {
-            // Original approach for smaller ranges
-            if end > self.queue.len() {
-                self.fill_queue(end);
+        let remaining = self.buffered_some_len();
+        remaining.saturating_sub(self.cursor)
+    }
+
+    /// Returns a reference to the last element of the iterator, or `None` if there are no
+    /// unconsumed elements, while buffering a bounded amount of elements.
+    ///
+    /// Returns [`PeekMoreError::LookaheadExceeded`] if the underlying iterator doesn't end within
+    /// `max_buffer` elements, which makes this method safe to use on infinite iterators. Note that
+    /// at most `max_buffer + 1` elements are buffered: one more element has to be taken from the
+    /// underlying iterator to find out it didn't end in time. No elements are consumed and the
+    /// cursor is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::{PeekMore, PeekMoreError};
+    ///
+    /// let mut finite = [1, 2, 3].into_iter().peekmore();
+    /// assert_eq!(finite.try_peek_last(3), Ok(Some(&3)));
+    ///
+    /// let mut infinite = core::iter::repeat(1).peekmore();
+    /// assert_eq!(infinite.try_peek_last(100), Err(PeekMoreError::LookaheadExceeded));
+    /// ```
+    ///
+    /// [`PeekMoreError::LookaheadExceeded`]: enum.PeekMoreError.html#variant.LookaheadExceeded
+    pub fn try_peek_last(&mut self, max_buffer: usize) -> Result<Option<&I::Item>, PeekMoreError> {
+        self.fill_queue(max_buffer);
+
+        if !self.is_queue_exhausted() {
+            return Err(PeekMoreError::LookaheadExceeded);
+        }
+
+        Ok(self.last_buffered())
+    }
+
+    /// Returns whether any unconsumed element is equal to `value`.
+    ///
+    /// Elements are buffered one at a time, and buffering stops as soon as a matching element is
+    /// found. If there is no match, the remaining elements of the underlying iterator are buffered,
+    /// so this method will never return `false` for an infinite iterator. No elements are consumed
+    /// and the cursor is not moved.
+    ///
+    /// **Note:** With a lookahead limit, only the elements up to the limit are searched, and `false`
+    /// is returned if the only matches lie beyond it.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// assert!(iter.contains_ahead(&2));
+    /// assert!(!iter.contains_ahead(&9));
+    /// ```
+    pub fn contains_ahead<T>(&mut self, value: &T) -> bool
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.position_ahead(value).is_some()
+    }
+
+    /// Returns a walk over the unconsumed elements, which buffers the elements one at a time as it
+    /// proceeds, and ends at the end of the iterator.
+    ///
+    /// Unlike [`peek_amount`], no more elements are buffered than the walk reaches, which makes
+    /// it possible to stop early. The walk starts at the first unconsumed element, regardless of
+    /// the cursor, and doesn't move the cursor. See [`Peeks`] for why it isn't an `Iterator`.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = (1..).peekmore();
+    /// let mut peeks = iter.peeks();
+    ///
+    /// while let Some(&value) = peeks.next_peek() {
+    ///     if value == 3 {
+    ///         break;
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(iter.buffered(), &[Some(1), Some(2), Some(3)]);
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    /// [`Peeks`]: struct.Peeks.html
+    pub fn peeks(&mut self) -> Peeks<'_, I> {
+        Peeks::new(self)
+    }
+
+    /// Returns the index of the first unconsumed element which is equal to `value`, or `None` if
+    /// there is no such element.
+    ///
+    /// The index is relative to the first unconsumed element, and doesn't take the position of the
+    /// cursor into account. Like [`contains_ahead`], buffering stops as soon as a matching element
+    /// is found, and with a lookahead limit, no elements beyond the limit are searched. No elements
+    /// are consumed and the cursor is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [10, 20, 30].iter().peekmore();
+    ///
+    /// assert_eq!(iter.position_ahead(&&20), Some(1));
+    /// assert_eq!(iter.position_ahead(&&40), None);
+    /// ```
+    ///
+    /// [`contains_ahead`]: struct.PeekMoreIterator.html#method.contains_ahead
+    pub fn position_ahead<T>(&mut self, value: &T) -> Option<usize>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        let mut index = 0;
+
+        loop {
+            if index == self.queue.len() {
+                self.push_next_to_queue();
+            }
+
+            match self.queue.get(index) {
+                Some(Some(element)) if element == value => return Some(index),
+                Some(Some(_)) => index += 1,
+                _ => return None,
             }
-            &self.queue.as_slice()[start..end]
         }
     }
 
-    /// Optimized peek_range implementation for large ranges using divide and conquer.
-    /// This method pre-allocates memory in chunks to reduce reallocation overhead.
-    fn peek_range_optimized(&mut self, start: usize, end: usize) -> &[Option<I::Item>] {
-        let current_len = self.queue.len();
+    /// Returns the index of the first unconsumed element for which `predicate` returns `false`,
+    /// using a binary search, like [`slice::partition_point`].
+    ///
+    /// The unconsumed elements are assumed to be partitioned according to `predicate`: all elements
+    /// for which it returns `true` come before all elements for which it returns `false`. If they
+    /// are not, the returned index is unspecified (but within bounds), as with
+    /// [`slice::partition_point`].
+    ///
+    /// The remaining elements of the underlying iterator are buffered first, so this method will never
+    /// return for an infinite iterator. With a lookahead limit, only the elements up to the limit are
+    /// buffered and searched. No elements are consumed and the cursor is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3, 4, 5].into_iter().peekmore();
+    /// assert_eq!(iter.peek_partition_point(|&x| x < 3), 2);
+    /// ```
+    ///
+    /// [`slice::partition_point`]: https://doc.rust-lang.org/core/primitive.slice.html#method.partition_point
+    pub fn peek_partition_point<P: Fn(&I::Item) -> bool>(&mut self, predicate: P) -> usize {
+        self.fill_queue_to_end();
 
-        if end > current_len {
-            // Calculate optimal chunk size based on range size
-            let range_size = end - current_len;
-            let chunk_size = if range_size > 10000 {
-                // Very large range - use larger chunks
-                2000
-            } else if range_size > 5000 {
-                // Large range - medium chunks
-                1000
+        let remaining = self.buffered_some_len();
+        self.queue[..remaining].partition_point(|v| v.as_ref().is_some_and(&predicate))
+    }
+
+    /// Fills the queue up to (including) the cursor.
+    ///
+    /// Filling stops early once the underlying iterator is exhausted, in which case a single `None`
+    /// is pushed to mark its end, and the queue is shorter than requested. Indices beyond the end
+    /// of the queue should be treated as `None`. Note that the queue may already end with several
+    /// `None`s, if it was padded by [`peek_range`].
+    ///
+    /// [`peek_range`]: struct.PeekMoreIterator.html#method.peek_range
+    #[inline]
+    fn fill_queue(&mut self, required_elements: usize) {
+        let stored_elements = self.queue.len();
+
+        if stored_elements <= required_elements && !self.is_queue_exhausted() {
+            // Use divide and conquer for large batches
+            let elements_needed = required_elements - stored_elements + 1;
+
+            if elements_needed > 1000 {
+                self.fill_queue_divide_conquer(required_elements);
             } else {
-                // Medium range - smaller chunks
-                500
-            };
+                for _ in stored_elements..=required_elements {
+                    if !self.push_next_to_queue() {
+                        break;
+                    }
+                }
+            }
+        }
 
-            // Fill queue in chunks using divide and conquer
-            self.fill_queue_in_chunks(current_len, end, chunk_size);
+        // only the pushed elements, and the element they follow, need to be checked
+        self.debug_assert_invariants_from(stored_elements.saturating_sub(1));
+    }
+
+    /// Fill queue using divide and conquer strategy for large batches.
+    /// This method reduces the overhead of repeated function calls for large numbers of elements.
+    fn fill_queue_divide_conquer(&mut self, required_elements: usize) {
+        let current_len = self.queue.len();
+        let remaining = required_elements - current_len + 1;
+
+        // For very large batches, use chunked processing
+        const CHUNK_SIZE: usize = 500;
+
+        if remaining > CHUNK_SIZE {
+            let chunks = remaining / CHUNK_SIZE;
+            let remainder = remaining % CHUNK_SIZE;
+
+            // Process full chunks
+            for _ in 0..chunks {
+                for _ in 0..CHUNK_SIZE {
+                    if !self.push_next_to_queue() {
+                        return;
+                    }
+                }
+            }
+
+            // Process remaining elements
+            for _ in 0..remainder {
+                if !self.push_next_to_queue() {
+                    return;
+                }
+            }
+        } else {
+            // For smaller batches, use the original approach
+            for _ in current_len..=required_elements {
+                if !self.push_next_to_queue() {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Fills the queue until the underlying iterator is exhausted, i.e. until the last element of
+    /// the queue is `None`.
+    ///
+    /// This never returns for infinite iterators.
+    fn fill_queue_to_end(&mut self) {
+        while self.push_next_to_queue() {}
+    }
+
+    /// Returns whether the last element of the queue is `None`, i.e. whether the underlying
+    /// iterator was found to be exhausted while filling the queue.
+    #[inline]
+    fn is_queue_exhausted(&self) -> bool {
+        matches!(self.queue.last(), Some(None))
+    }
+
+    /// Returns whether the queue holds as many elements as the lookahead limit allows.
+    #[inline]
+    fn is_queue_at_limit(&self) -> bool {
+        self.limit.is_some_and(|max| self.queue.len() >= max)
+    }
+
+    /// Consume the underlying iterator and push an element to the queue.
+    ///
+    /// Returns `false` if the underlying iterator is exhausted, in which case nothing is pushed if
+    /// the queue already ends with `None`, and a single `None` is pushed otherwise.
+    /// Also returns `false`, without pushing anything, if the queue is at its lookahead limit.
+    #[inline]
+    fn push_next_to_queue(&mut self) -> bool {
+        if self.is_queue_exhausted() || self.is_queue_at_limit() {
+            return false;
+        }
+
+        if let GrowthStrategy::Linear(step) = self.growth
+            && self.queue.len() == self.queue.capacity()
+        {
+            self.queue.reserve_exact(step.max(1));
+        }
+
+        let item = self.next_from_iterator();
+        let is_some = item.is_some();
+        self.queue.push(item);
+
+        is_some
+    }
+
+    /// Take the next element from the underlying iterator, or if it ran out of elements, from the
+    /// elements which were taken from its back.
+    #[inline]
+    fn next_from_iterator(&mut self) -> Option<I::Item> {
+        self.iterator.next().or_else(|| self.back_queue.pop())
+    }
+
+    /// Increment the cursor which points to the current peekable item.
+    /// Note: if the cursor is [core::usize::MAX], it will not increment any further.
+    ///
+    /// [core::usize::MAX]: https://doc.rust-lang.org/core/usize/constant.MAX.html
+    #[inline]
+    fn increment_cursor(&mut self) {
+        // do not overflow
+        self.cursor = self.cursor.saturating_add(1);
+    }
+
+    /// Decrement the cursor which points to the current peekable item.
+    /// Note: if the cursor is [core::usize::MIN], it will not decrement any further.
+    ///
+    /// [core::usize::MIN]: https://doc.rust-lang.org/core/usize/constant.MIN.html
+    #[inline]
+    fn decrement_cursor(&mut self) {
+        if self.cursor > usize::MIN {
+            self.cursor -= 1;
+        }
+    }
+
+    /// Remove all elements from the start of the iterator until reaching the same
+    /// position as the cursor by calling `Iterator::next()`.
+    ///
+    /// After calling this method, `iter.peek() == iter.next().as_ref()`.
+    ///
+    ///```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3, 4];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// iter.advance_cursor_by(2);
+    /// assert_eq!(iter.peek(), Some(&&3));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// iter.truncate_iterator_to_cursor();
+    /// assert_eq!(iter.peek(), Some(&&3));
+    /// assert_eq!(iter.next(), Some(&3));
+    ///```
+    pub fn truncate_iterator_to_cursor(&mut self) {
+        if self.cursor < self.queue.len() {
+            self.consumed += self.queue.drain(0..self.cursor).flatten().count();
+        } else {
+            self.consumed += self.buffered_some_len();
+
+            // if the cursor is greater than the queue length,
+            // we want to remove the overflow from the iterator
+            if !self.is_queue_exhausted() {
+                for _ in 0..self.cursor.saturating_sub(self.queue.len()) {
+                    if self.next_from_iterator().is_none() {
+                        break;
+                    }
+                    self.consumed += 1;
+                }
+            }
+            self.queue.clear();
+        }
+
+        self.cursor = 0;
+    }
+
+    /// Drop the buffered elements at queue indices `len` and beyond, and return the dropped elements.
+    ///
+    /// **Note:** The dropped elements have already been taken from the underlying iterator and can't
+    /// be put back. After truncating, the iterator continues with the elements the underlying iterator
+    /// has not yet produced, so the dropped elements are lost to the `PeekMoreIterator`. They are
+    /// returned so they are not silently discarded.
+    ///
+    /// If `len` is greater than or equal to the amount of buffered elements, nothing is dropped.
+    /// The cursor is not moved. The dropped elements are counted as [`consumed`], since they have
+    /// been taken from the input.
+    ///
+    ///```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3, 4, 5];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_nth(3), Some(&&4));
+    /// assert_eq!(iter.truncate_queue_at(2), vec![&3, &4]);
+    ///
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&5));
+    ///```
+    ///
+    /// [`consumed`]: struct.PeekMoreIterator.html#method.consumed
+    pub fn truncate_queue_at(&mut self, len: usize) -> Vec<I::Item> {
+        if len >= self.queue.len() {
+            return Vec::new();
+        }
+
+        let dropped: Vec<I::Item> = self.queue.drain(len..).flatten().collect();
+        self.consumed += dropped.len();
+        self.debug_assert_invariants_from(len);
+
+        dropped
+    }
+
+    /// Replace the unconsumed element at queue index `n` with `value`, and return the replaced
+    /// element.
+    ///
+    /// The queue is buffered up to index `n`. If there is no element at index `n`, nothing is
+    /// replaced, and `None` is returned. The cursor is not moved.
+    ///
+    ///```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.replace_peeked(1, 99), Some(2));
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![1, 99, 3]);
+    ///```
+    pub fn replace_peeked(&mut self, n: usize, value: I::Item) -> Option<I::Item> {
+        self.fill_queue(n);
+
+        let element = self.queue.get_mut(n)?.as_mut()?;
+        Some(core::mem::replace(element, value))
+    }
+
+    /// Insert `value` into the queue at index `n`, shifting the unconsumed elements at and beyond
+    /// index `n` back by one.
+    ///
+    /// The queue is buffered up to index `n`. If fewer than `n` elements can be buffered, `value`
+    /// is inserted after the last buffered element instead. If the cursor pointed at or beyond the
+    /// insertion index, it's moved along, so it keeps pointing at the same element. The inserted
+    /// element may take the queue beyond its lookahead limit, if there is one.
+    ///
+    ///```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// iter.insert_peeked(1, 99);
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![1, 99, 2, 3]);
+    ///```
+    pub fn insert_peeked(&mut self, n: usize, value: I::Item) {
+        if n > 0 {
+            self.fill_queue(n - 1);
+        }
+
+        // never insert behind the `None` which marks the end of the underlying iterator
+        let index = n.min(self.buffered_some_len());
+        self.queue.insert(index, Some(value));
+
+        if self.cursor >= index {
+            self.increment_cursor();
         }
 
-        &self.queue.as_slice()[start..end]
+        self.debug_assert_invariants();
     }
 
-    /// Fill the queue in chunks using divide and conquer strategy.
-    /// This reduces memory reallocation overhead for large ranges.
-    fn fill_queue_in_chunks(&mut self, current_end: usize, target_end: usize, chunk_size: usize) {
-        let mut current_pos = current_end;
+    /// Remove the unconsumed element at queue index `n` from the queue, and return it.
+    ///
+    /// The queue is buffered up to index `n`. If there is no element at index `n`, nothing is
+    /// removed, and `None` is returned. If the cursor pointed beyond the removed element, it's moved
+    /// back, so it keeps pointing at the same element; if it pointed at the removed element, it
+    /// points at the element which followed it afterwards.
+    ///
+    ///```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.remove_peeked(1), Some(2));
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![1, 3]);
+    ///```
+    pub fn remove_peeked(&mut self, n: usize) -> Option<I::Item> {
+        self.peek_nth(n)?;
+
+        if self.cursor > n {
+            self.decrement_cursor();
+        }
+
+        let element = self.queue.remove(n);
+        self.debug_assert_invariants();
+
+        element
+    }
+
+    /// Returns an iterator which consumes exactly the elements which are currently buffered.
+    ///
+    /// No new elements are taken from the underlying iterator: the returned iterator ends once the
+    /// buffered elements have been consumed. The cursor moves along with the consumed elements, as
+    /// it does for [`next()`].
+    ///
+    ///```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = (1..10).peekmore();
+    /// iter.peek_nth(2);
+    ///
+    /// assert_eq!(iter.take_peeked().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// assert_eq!(iter.next(), Some(4));
+    ///```
+    ///
+    /// [`next()`]: struct.PeekMoreIterator.html#impl-Iterator
+    pub fn take_peeked(&mut self) -> impl Iterator<Item = I::Item> + '_ {
+        let buffered = self.buffered_some_len();
+
+        self.by_ref().take(buffered)
+    }
+
+    /// Drop all buffered elements, return them, and reset the cursor.
+    ///
+    /// Afterwards, the iterator continues with the elements the underlying iterator has not yet
+    /// produced. Since the buffered elements can't be put back into the underlying iterator, they
+    /// are returned so they are not silently discarded. Like for [`truncate_queue_at`], the
+    /// returned elements are counted as [`consumed`].
+    ///
+    ///```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = (1..10).peekmore();
+    /// iter.advance_cursor_to(2);
+    ///
+    /// assert_eq!(iter.clear_buffer(), vec![1, 2, 3]);
+    /// assert_eq!(iter.cursor(), 0);
+    /// assert_eq!(iter.absolute_cursor(), 3);
+    /// assert_eq!(iter.next(), Some(4));
+    ///```
+    ///
+    /// [`truncate_queue_at`]: struct.PeekMoreIterator.html#method.truncate_queue_at
+    /// [`consumed`]: struct.PeekMoreIterator.html#method.consumed
+    pub fn clear_buffer(&mut self) -> Vec<I::Item> {
+        self.reset_cursor();
+        self.truncate_queue_at(0)
+    }
+
+    /// Removes consecutive buffered elements which resolve to the same key, keeping the first
+    /// element of each run, like [`Vec::dedup_by_key`].
+    ///
+    /// Only elements which are already buffered are considered; no new elements are taken from the
+    /// underlying iterator. If the cursor pointed at a removed element, it will point to the first
+    /// element of its run afterwards; otherwise it keeps pointing at the same element.
+    ///
+    ///```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [("a", 1), ("a", 2), ("b", 3)];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// let _ = iter.peek_amount(3);
+    /// iter.dedup_buffered_by_key(|&&(name, _)| name);
+    ///
+    /// assert_eq!(iter.next(), Some(&("a", 1)));
+    /// assert_eq!(iter.next(), Some(&("b", 3)));
+    ///```
+    ///
+    /// [`Vec::dedup_by_key`]: https://doc.rust-lang.org/alloc/vec/struct.Vec.html#method.dedup_by_key
+    pub fn dedup_buffered_by_key<K: PartialEq, F: FnMut(&I::Item) -> K>(&mut self, mut key: F) {
+        let cursor = self.cursor;
+        let mut previous_key: Option<K> = None;
+        let mut index = 0;
+        let mut removed_up_to_cursor = 0;
+
+        self.queue.retain(|v| {
+            let keep = match v {
+                Some(item) => {
+                    let current = key(item);
+                    let duplicate = previous_key.as_ref() == Some(&current);
+                    previous_key = Some(current);
+                    !duplicate
+                }
+                None => {
+                    previous_key = None;
+                    true
+                }
+            };
+
+            if !keep && index <= cursor {
+                removed_up_to_cursor += 1;
+            }
+            index += 1;
+
+            keep
+        });
+
+        self.cursor -= removed_up_to_cursor;
+    }
+
+    /// Split the iterator at the cursor into the elements before the cursor and an iterator
+    /// which starts at the element the cursor points to.
+    ///
+    /// The elements before the cursor are buffered (if they weren't already) and moved out of the
+    /// queue into an owned `Vec`. Elements which don't exist (because the underlying iterator ran
+    /// out) are skipped. The cursor of the returned iterator points to its first unconsumed
+    /// element.
+    ///
+    /// **Note:** The items are required to implement `Clone`, so the owned prefix can be handed off
+    /// and duplicated independently of the returned iterator.
+    ///
+    /// The split is always made at the cursor: a lookahead limit (see
+    /// [`PeekMore::peekmore_limited`]) doesn't stop the elements before the cursor from being
+    /// buffered, since they are moved out of the queue right away.
+    ///
+    ///```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3, 4];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// iter.advance_cursor_by(2);
+    /// let (prefix, mut rest) = iter.split_at_cursor();
+    /// assert_eq!(prefix, vec![&1, &2]);
+    /// assert_eq!(rest.peek(), Some(&&3));
+    /// assert_eq!(rest.next(), Some(&3));
+    ///```
+    ///
+    /// [`PeekMore::peekmore_limited`]: trait.PeekMore.html#method.peekmore_limited
+    pub fn split_at_cursor(mut self) -> (Vec<I::Item>, PeekMoreIterator<I>)
+    where
+        I::Item: Clone,
+    {
+        if self.cursor == 0 {
+            return (Vec::new(), self);
+        }
+
+        // fill up to the cursor regardless of the lookahead limit, so the split lands at the cursor
+        let limit = self.limit.take();
+        self.fill_queue(self.cursor - 1);
+        self.limit = limit;
+
+        let end = self.cursor.min(self.queue.len());
+        let prefix: Vec<I::Item> = self.queue.drain(0..end).flatten().collect();
+        self.consumed += prefix.len();
+        self.cursor = 0;
+
+        (prefix, self)
+    }
+
+    /// Returns a view into the next `start` (inclusive) to `end` (exclusive) elements.
+    ///
+    /// **Note:** `start` and `end` represent indices and start at `0`. These indices always start
+    /// at the beginning of the queue (the unconsumed iterator) and don't take the position of the cursor
+    /// into account.
+    ///
+    /// The view always holds exactly `end - start` elements. If the underlying iterator runs out of
+    /// elements, the remainder of the view is padded with `None`s. Since the view borrows from the
+    /// queue, the padding is stored in the queue, up to index `end`: it takes memory proportional to
+    /// `end`, and is counted by [`buffered_len`] until it is consumed. Without a lookahead limit,
+    /// peeking a huge range past the end of a short iterator will therefore allocate a queue of
+    /// that size. The only exception is a lookahead limit (see [`PeekMore::peekmore_limited`]):
+    /// then neither the buffered elements nor the padding grow the queue past the limit, and the
+    /// view, which is cut off at the limit, may be shorter than `end - start` elements.
+    ///
+    /// # Panics
+    ///
+    /// **Panics** if `start > end`, in which case the range would be negative.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3, 4];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// match iter.peek_range(1, 3) {
+    ///     [Some(2), Some(p)] => println!("Yay! we found number {} after number 2", p),
+    ///     _ => println!("Oh noes!"),
+    /// }
+    /// ```
+    ///
+    /// [`buffered_len`]: struct.PeekMoreIterator.html#method.buffered_len
+    /// [`PeekMore::peekmore_limited`]: trait.PeekMore.html#method.peekmore_limited
+    // implementation choice:
+    // why not `core::ops::RangeBound<T>`? it adds unnecessary complexity since we would need to define what
+    // unbounded bounds mean (e.g. for end whether it would be the end of the queue or the unconsumed iterator
+    // elements until None or that it won't be allowed, or some other definition), we would need to map
+    // the range Inclusive and Exclusive and Unbound-ed elements to usize, and we would need to verify
+    // that T would be an unsigned integer. Using RangeBound would not be all negative though since we
+    // could then use the standard Rust range syntax options such as 0..4 or 0..=3, which clearly
+    // tell a user what kind of bounds are used (inclusive, exclusive, etc.)
+    // For now however, for the reason of not adding unnecessary complexity, I've decided
+    // that the simplicity of concrete start and end types is the better choice.
+    pub fn peek_range(&mut self, start: usize, end: usize) -> &[Option<I::Item>] {
+        assert!(
+            start <= end,
+            "range of the peeked view [start, end] should be positive (i.e. start <= end)"
+        );
+
+        // For large ranges, use divide and conquer optimization
+        let range_size = end - start;
+        if range_size > 2000 {
+            self.fill_queue_for_range_optimized(end);
+        } else {
+            // Original approach for smaller ranges
+            if end > self.queue.len() {
+                self.fill_queue(end);
+            }
+        }
+
+        // The queue is shorter than `end` if the underlying iterator ran out of elements, in which
+        // case the view is padded with `None`s, or if the lookahead limit was reached. The padding
+        // never grows the queue past the lookahead limit.
+        let padded_len = self.limit.map_or(end, |max| end.min(max));
+        if self.queue.len() < padded_len && self.is_queue_exhausted() {
+            self.queue.resize_with(padded_len, || None);
+        }
+
+        let len = self.queue.len();
+        &self.queue.as_slice()[start.min(len)..end.min(len)]
+    }
+
+    /// Returns a view into the next `start` (inclusive) to `end` (exclusive) elements, like
+    /// [`peek_range`], but returns a [`PeekMoreError::InvalidRange`] instead of panicking if
+    /// `start > end`.
+    ///
+    /// ```
+    /// use obsessive_peek::{PeekMore, PeekMoreError};
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.try_peek_range(1, 3), Ok(&[Some(2), Some(3)][..]));
+    /// assert_eq!(
+    ///     iter.try_peek_range(2, 1),
+    ///     Err(PeekMoreError::InvalidRange { start: 2, end: 1 })
+    /// );
+    /// ```
+    ///
+    /// [`peek_range`]: struct.PeekMoreIterator.html#method.peek_range
+    /// [`PeekMoreError::InvalidRange`]: enum.PeekMoreError.html#variant.InvalidRange
+    pub fn try_peek_range(
+        &mut self,
+        start: usize,
+        end: usize,
+    ) -> Result<&[Option<I::Item>], PeekMoreError> {
+        if start > end {
+            return Err(PeekMoreError::InvalidRange { start, end });
+        }
+
+        Ok(self.peek_range(start, end))
+    }
+
+    /// Returns an owned copy of the view into the next `start` (inclusive) to `end` (exclusive)
+    /// elements, as returned by [`peek_range`].
+    ///
+    /// Since the copy doesn't borrow the iterator, the iterator can be used again while the view is
+    /// still around.
+    ///
+    /// # Panics
+    ///
+    /// **Panics** if `start > end`, like [`peek_range`].
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// let view = iter.peek_range_copied(0, 2);
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(view, vec![Some(1), Some(2)]);
+    /// ```
+    ///
+    /// [`peek_range`]: struct.PeekMoreIterator.html#method.peek_range
+    pub fn peek_range_copied(&mut self, start: usize, end: usize) -> Vec<Option<I::Item>>
+    where
+        I::Item: Copy,
+    {
+        self.peek_range(start, end).to_vec()
+    }
+
+    /// Optimized queue filling for large ranges of peek_range, using divide and conquer.
+    /// This method pre-allocates memory in chunks to reduce reallocation overhead.
+    fn fill_queue_for_range_optimized(&mut self, end: usize) {
+        let current_len = self.queue.len();
+
+        if end > current_len {
+            // Calculate optimal chunk size based on range size
+            let range_size = end - current_len;
+            let chunk_size = if range_size > 10000 {
+                // Very large range - use larger chunks
+                2000
+            } else if range_size > 5000 {
+                // Large range - medium chunks
+                1000
+            } else {
+                // Medium range - smaller chunks
+                500
+            };
+
+            // Fill queue in chunks using divide and conquer
+            self.fill_queue_in_chunks(current_len, end, chunk_size);
+        }
+    }
+
+    /// Fill the queue in chunks using divide and conquer strategy.
+    /// This reduces memory reallocation overhead for large ranges.
+    fn fill_queue_in_chunks(&mut self, current_end: usize, target_end: usize, chunk_size: usize) {
+        let mut current_pos = current_end;
+
+        while current_pos < target_end {
+            let next_end = (current_pos + chunk_size).min(target_end);
+
+            // Fill this chunk
+            for _ in current_pos..next_end {
+                if !self.push_next_to_queue() {
+                    return;
+                }
+            }
+
+            current_pos = next_end;
+        }
+    }
+
+    /// Returns a view into the next `n` unconsumed elements of the iterator.
+    ///
+    /// Here, `n` represents the amount of elements as counted from the start of the unconsumed iterator.
+    ///
+    /// For example, if we created a (peekmore) iterator from the array `[1, 2, 3]` and consume the first
+    /// element by calling the regular `Iterator::next` method, and then call `peek_amount(3)`, the iterator will
+    /// return `&[Some(2), Some(3), None]`. Here `Some(2)` and `Some(3)` are queued elements which
+    /// we can peek at, and are not consumed by the iterator yet. `None` is the last element returned by
+    /// our view, since our original iterator is sized and doesn't contain more elements. Thus in the absence
+    /// of additional elements, we return `None`. This method is a variation on [`peek_range`].
+    /// You could instead have called `peek_range(0, n)` (note that `peek_range` takes indices as arguments
+    /// instead of an amount).
+    ///
+    /// Like for [`peek_range`], the `None`s which pad the view are stored in the queue, up to the
+    /// lookahead limit if there is one.
+    ///
+    /// **Note:** This method does not use or modify the position of the cursor.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let iterable = [1, 2, 3];
+    /// let mut iter = iterable.iter().peekmore();
+    ///
+    /// match iter.peek_amount(4) { // -> &[Option(&1), Option(&2), Option(&3), None]
+    ///   [Some(a), Some(b), Some(c), None] => println!("Found a match ({}, {}, {}) ", a, b, c),
+    ///   _ => eprintln!("Expected (just) 3 more values"),
+    /// }
+    /// ```
+    ///
+    /// [`peek_range`]: struct.PeekMoreIterator.html#method.peek_range
+    #[inline]
+    pub fn peek_amount(&mut self, n: usize) -> &[Option<I::Item>] {
+        self.peek_range(0, n)
+    }
+
+    /// Buffers the next `n` unconsumed elements, and calls `f` with a mutable view of them,
+    /// returning the result of `f`.
+    ///
+    /// The view is the same as the one returned by [`peek_amount`], but the elements can be edited
+    /// or reordered in place, and changes are visible to subsequent peeks and consumption. Take care
+    /// not to move a `None` in front of a `Some`: a `None` marks the end of the underlying iterator,
+    /// and the elements behind it may be skipped. The cursor is not moved.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [3, 1, 2].into_iter().peekmore();
+    ///
+    /// let first = iter.with_peeked_mut(2, |window| {
+    ///     window.swap(0, 1);
+    ///     window[0]
+    /// });
+    ///
+    /// assert_eq!(first, Some(1));
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn with_peeked_mut<R>(
+        &mut self,
+        n: usize,
+        f: impl FnOnce(&mut [Option<I::Item>]) -> R,
+    ) -> R {
+        let len = self.peek_amount(n).len();
+
+        f(&mut self.queue[..len])
+    }
+
+    /// Returns the first of the next `n` unconsumed elements, together with a view of the rest of
+    /// them, like [`slice::split_first`]. Returns `None` if there is no unconsumed element, or if
+    /// `n` is `0`.
+    ///
+    /// The view of the rest is the same as the one [`peek_amount`] returns, minus its first
+    /// element. Like [`peek_amount`], this method neither uses nor modifies the position of the
+    /// cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_split_first(3), Some((&1, &[Some(2), Some(3)][..])));
+    /// ```
+    ///
+    /// [`slice::split_first`]: https://doc.rust-lang.org/core/primitive.slice.html#method.split_first
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_split_first(&mut self, n: usize) -> Option<SplitFirst<'_, I::Item>> {
+        let (first, rest) = self.peek_amount(n).split_first()?;
+
+        Some((first.as_ref()?, rest))
+    }
+
+    /// Returns a reference to the element with the minimum key among the next `n` unconsumed
+    /// elements, or `None` if there are no such elements.
+    ///
+    /// If several elements are equally minimum, the first one is returned. Like [`peek_amount`],
+    /// this method neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [3, 1, 4, 1, 5].into_iter().peekmore();
+    /// assert_eq!(iter.peek_min_by_key(4, |&v| v), Some(&1));
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_min_by_key<K: Ord, F: Fn(&I::Item) -> K>(
+        &mut self,
+        n: usize,
+        key: F,
+    ) -> Option<&I::Item> {
+        self.peek_window(n).min_by_key(|v| key(v))
+    }
+
+    /// Returns a reference to the element with the maximum key among the next `n` unconsumed
+    /// elements, or `None` if there are no such elements.
+    ///
+    /// If several elements are equally maximum, the first one is returned (unlike
+    /// [`Iterator::max_by_key`], which returns the last). Like [`peek_amount`], this method
+    /// neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [3, 1, 4, 1, 5].into_iter().peekmore();
+    /// assert_eq!(iter.peek_max_by_key(4, |&v| v), Some(&4));
+    /// ```
+    ///
+    /// [`Iterator::max_by_key`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.max_by_key
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_max_by_key<K: Ord, F: Fn(&I::Item) -> K>(
+        &mut self,
+        n: usize,
+        key: F,
+    ) -> Option<&I::Item> {
+        self.peek_window(n).min_by(|a, b| key(b).cmp(&key(a)))
+    }
+
+    /// Returns the index (relative to the first unconsumed element) of the minimum among the next
+    /// `n` unconsumed elements, or `None` if there are no such elements.
+    ///
+    /// If several elements are equally minimum, the index of the first one is returned. Unlike
+    /// [`peek_min_by_key`], no reference is returned, so the iterator can be used again right away.
+    /// Like [`peek_amount`], this method neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [3, 1, 4, 1].into_iter().peekmore();
+    /// assert_eq!(iter.peek_argmin(4), Some(1));
+    /// ```
+    ///
+    /// [`peek_min_by_key`]: struct.PeekMoreIterator.html#method.peek_min_by_key
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_argmin(&mut self, n: usize) -> Option<usize>
+    where
+        I::Item: Ord,
+    {
+        self.peek_window(n)
+            .enumerate()
+            .min_by(|(_, a), (_, b)| a.cmp(b))
+            .map(|(index, _)| index)
+    }
+
+    /// Returns the index (relative to the first unconsumed element) of the maximum among the next
+    /// `n` unconsumed elements, or `None` if there are no such elements.
+    ///
+    /// If several elements are equally maximum, the index of the first one is returned. Unlike
+    /// [`peek_max_by_key`], no reference is returned, so the iterator can be used again right away.
+    /// Like [`peek_amount`], this method neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [3, 1, 4, 1].into_iter().peekmore();
+    /// assert_eq!(iter.peek_argmax(4), Some(2));
+    /// ```
+    ///
+    /// [`peek_max_by_key`]: struct.PeekMoreIterator.html#method.peek_max_by_key
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_argmax(&mut self, n: usize) -> Option<usize>
+    where
+        I::Item: Ord,
+    {
+        self.peek_window(n)
+            .enumerate()
+            .min_by(|(_, a), (_, b)| b.cmp(a))
+            .map(|(index, _)| index)
+    }
+
+    /// Folds over the next `n` unconsumed elements while carrying the state `init`, and collects
+    /// the values produced by `f`, similar to [`Iterator::scan`].
+    ///
+    /// Collecting stops when `f` returns `None`, or when the window of `n` elements (or the
+    /// underlying iterator) ends. No elements are consumed and the cursor is not moved.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().peekmore();
+    ///
+    /// // Running sums, until the sum exceeds 5.
+    /// let sums = iter.peek_scan(4, 0, |sum, &v| {
+    ///     *sum += v;
+    ///     (*sum <= 5).then_some(*sum)
+    /// });
+    /// assert_eq!(sums, vec![1, 3]);
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    ///
+    /// [`Iterator::scan`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.scan
+    pub fn peek_scan<St, B, F: FnMut(&mut St, &I::Item) -> Option<B>>(
+        &mut self,
+        n: usize,
+        init: St,
+        mut f: F,
+    ) -> Vec<B> {
+        self.peek_window(n)
+            .scan(init, |state, v| f(state, v))
+            .collect()
+    }
+
+    /// Returns references to up to `n` unconsumed elements, taking every `step`-th element starting
+    /// with the first unconsumed element (i.e. at indices `0`, `step`, `2 * step`, ...).
+    ///
+    /// Sampling stops early if the underlying iterator runs out of elements. Like [`peek_amount`],
+    /// this method neither uses nor modifies the position of the cursor.
+    ///
+    /// # Panics
+    ///
+    /// **Panics** if `step` is `0`.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = (0..6).peekmore();
+    /// assert_eq!(iter.peek_step_by(3, 2), vec![&0, &2, &4]);
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_step_by(&mut self, n: usize, step: usize) -> Vec<&I::Item> {
+        assert!(step > 0, "step should be greater than zero");
+
+        if n == 0 {
+            return Vec::new();
+        }
+
+        self.fill_queue((n - 1).saturating_mul(step));
+        self.queue
+            .iter()
+            .step_by(step)
+            .take(n)
+            .map_while(Option::as_ref)
+            .collect()
+    }
+
+    /// Returns an iterator over references to the next `n` unconsumed elements, in reverse order.
+    ///
+    /// Only the elements which exist are yielded: if the underlying iterator runs out of elements
+    /// within the window, the iterator starts at the last element instead. Unlike
+    /// `DoubleEndedIterator`, this only reverses the lookahead window, not the whole iterator.
+    /// Like [`peek_amount`], this method neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    ///
+    /// let reversed: Vec<&i32> = iter.peek_rev(3).collect();
+    /// assert_eq!(reversed, vec![&3, &2, &1]);
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_rev(&mut self, n: usize) -> impl Iterator<Item = &I::Item> {
+        if n > 0 {
+            self.fill_queue(n - 1);
+        }
+
+        let len = self
+            .queue
+            .iter()
+            .take(n)
+            .take_while(|v| v.is_some())
+            .count();
+        self.queue[..len].iter().rev().flatten()
+    }
+
+    /// Returns the run-length encoding of the next `n` unconsumed elements, as `(value, count)`
+    /// pairs where `count` is the length of a run of equal consecutive elements.
+    ///
+    /// Only the elements which exist are encoded. The values are cloned out of the queue; like
+    /// [`peek_amount`], this method neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 1, 2, 3, 3, 3].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_rle(6), vec![(1, 2), (2, 1), (3, 3)]);
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_rle(&mut self, n: usize) -> Vec<(I::Item, usize)>
+    where
+        I::Item: Clone + PartialEq,
+    {
+        let mut runs: Vec<(I::Item, usize)> = Vec::new();
+
+        for element in self.peek_window(n) {
+            match runs.last_mut() {
+                Some((value, count)) if value == element => *count += 1,
+                _ => runs.push((element.clone(), 1)),
+            }
+        }
+
+        runs
+    }
+
+    /// Returns references to the first `n` unconsumed elements for which `keep` returns `true`,
+    /// skipping the elements for which it returns `false`.
+    ///
+    /// Elements are buffered until `n` matching elements are found, or until the end of the
+    /// iterator. If there are fewer than `n` matching elements, the remaining elements of the
+    /// underlying iterator are buffered, so this method will never return for an infinite iterator
+    /// in that case. Like [`peek_amount`], this method neither uses nor modifies the position of
+    /// the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "a b c".chars().peekmore();
+    ///
+    /// assert_eq!(iter.peek_filtered(2, |c| !c.is_whitespace()), vec![&'a', &'b']);
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_filtered<P: Fn(&I::Item) -> bool>(&mut self, n: usize, keep: P) -> Vec<&I::Item> {
+        let mut len = 0;
+        let mut found = 0;
+
+        while found < n {
+            match self.peek_nth(len) {
+                Some(element) => {
+                    if keep(element) {
+                        found += 1;
+                    }
+                    len += 1;
+                }
+                None => break,
+            }
+        }
+
+        self.queue[..len]
+            .iter()
+            .flatten()
+            .filter(|element| keep(element))
+            .collect()
+    }
+
+    /// Returns the smallest period `p <= max_period` with which the next unconsumed elements
+    /// repeat, or `None` if there is no such period.
+    ///
+    /// Up to `2 * max_period` elements are buffered, and a period `p` is only reported if at least
+    /// `2 * p` elements exist, i.e. if the repetition is actually visible. Like [`peek_amount`],
+    /// this method neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 1, 2, 1, 2].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_period(3), Some(2));
+    /// assert_eq!(iter.peek_period(1), None);
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_period(&mut self, max_period: usize) -> Option<usize>
+    where
+        I::Item: PartialEq,
+    {
+        let len = self.peek_window(max_period.saturating_mul(2)).count();
+        let window = &self.queue[..len];
+
+        // a period is only visible if it repeats at least once within the window
+        (1..=max_period.min(len / 2)).find(|&period| {
+            window
+                .iter()
+                .zip(&window[period..])
+                .all(|(earlier, later)| earlier == later)
+        })
+    }
+
+    /// Returns how often each value occurs among the next `n` unconsumed elements.
+    ///
+    /// Only the elements which exist are counted. The values are cloned out of the queue; like
+    /// [`peek_amount`], this method neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "abbccc".chars().peekmore();
+    /// let counts = iter.peek_counts(6);
+    ///
+    /// assert_eq!(counts[&'a'], 1);
+    /// assert_eq!(counts[&'c'], 3);
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_counts(&mut self, n: usize) -> BTreeMap<I::Item, usize>
+    where
+        I::Item: Ord + Clone,
+    {
+        let mut counts = BTreeMap::new();
+
+        for element in self.peek_window(n) {
+            *counts.entry(element.clone()).or_insert(0) += 1;
+        }
+
+        counts
+    }
+
+    /// Splits the next `n` unconsumed elements into chunks of consecutive elements, like
+    /// [`slice::chunk_by`]. A new chunk starts wherever `same_group(previous, next)` returns `false`.
+    ///
+    /// Only the elements which exist are chunked. Like [`peek_amount`], this method neither uses
+    /// nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 4, 5, 7].into_iter().peekmore();
+    ///
+    /// let chunks = iter.peek_chunks_by(5, |a, b| b - a <= 1);
+    /// assert_eq!(chunks, vec![&[Some(1), Some(2)][..], &[Some(4), Some(5)], &[Some(7)]]);
+    /// ```
+    ///
+    /// [`slice::chunk_by`]: https://doc.rust-lang.org/core/primitive.slice.html#method.chunk_by
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_chunks_by<P: Fn(&I::Item, &I::Item) -> bool>(
+        &mut self,
+        n: usize,
+        same_group: P,
+    ) -> Vec<&[Option<I::Item>]> {
+        let len = self.peek_window(n).count();
+
+        self.queue[..len]
+            .chunk_by(|previous, next| match (previous, next) {
+                (Some(previous), Some(next)) => same_group(previous, next),
+                _ => false,
+            })
+            .collect()
+    }
+
+    /// Returns whether the next `n` unconsumed elements are all equal to each other.
+    ///
+    /// Only the elements which exist are compared, so a window holding at most one element is
+    /// always equal. Like [`peek_amount`], this method neither uses nor modifies the position of
+    /// the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [5, 5, 6].into_iter().peekmore();
+    ///
+    /// assert!(iter.peek_all_equal(2));
+    /// assert!(!iter.peek_all_equal(3));
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_all_equal(&mut self, n: usize) -> bool
+    where
+        I::Item: PartialEq,
+    {
+        let mut window = self.peek_window(n);
+
+        match window.next() {
+            Some(first) => window.all(|element| element == first),
+            None => true,
+        }
+    }
+
+    /// Returns whether the next `n` unconsumed elements are sorted, according to `in_order`.
+    ///
+    /// Each pair of adjacent elements `(a, b)` must satisfy `in_order(a, b)`, so for example
+    /// `|a, b| a >= b` checks for a descending order. Only the elements which exist are compared, so
+    /// a window holding at most one element is always sorted. Like [`peek_amount`], this method
+    /// neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [3, 2, 4].into_iter().peekmore();
+    ///
+    /// assert!(iter.is_peeked_sorted_by(2, |a, b| a >= b));
+    /// assert!(!iter.is_peeked_sorted_by(3, |a, b| a >= b));
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn is_peeked_sorted_by<F>(&mut self, n: usize, in_order: F) -> bool
+    where
+        F: Fn(&I::Item, &I::Item) -> bool,
+    {
+        let len = self.peek_window(n).count();
+
+        self.queue[..len].windows(2).all(|pair| match pair {
+            [Some(previous), Some(next)] => in_order(previous, next),
+            _ => true,
+        })
+    }
+
+    /// Reduces the next `n` unconsumed elements to a single value, by repeatedly applying `f` to
+    /// the accumulated value and the next element, or returns `None` if there are no such elements.
+    ///
+    /// The first element (cloned out of the queue) is the initial accumulated value. If `f` returns
+    /// [`ControlFlow::Break`], the reduction stops early, and the value it holds is returned.
+    /// Like [`peek_amount`], this method neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use core::ops::ControlFlow;
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [3, 7, 0, 9].into_iter().peekmore();
+    ///
+    /// // the maximum up to the first 0
+    /// let max = iter.peek_reduce(4, |max, &v| match v {
+    ///     0 => ControlFlow::Break(max),
+    ///     _ => ControlFlow::Continue(max.max(v)),
+    /// });
+    /// assert_eq!(max, Some(7));
+    /// ```
+    ///
+    /// [`ControlFlow::Break`]: https://doc.rust-lang.org/core/ops/enum.ControlFlow.html#variant.Break
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_reduce<F>(&mut self, n: usize, f: F) -> Option<I::Item>
+    where
+        I::Item: Clone,
+        F: FnMut(I::Item, &I::Item) -> ControlFlow<I::Item, I::Item>,
+    {
+        let mut window = self.peek_window(n);
+        let first = window.next()?.clone();
+
+        match window.try_fold(first, f) {
+            ControlFlow::Continue(value) | ControlFlow::Break(value) => Some(value),
+        }
+    }
+
+    /// Returns the sum of the next `n` unconsumed elements.
+    ///
+    /// Only the elements which exist are summed; the sum of an empty window is the zero value of
+    /// the element type. The elements are cloned out of the queue; like [`peek_amount`], this
+    /// method neither uses nor modifies the position of the cursor.
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_window_sum(3), 6);
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    ///
+    /// [`peek_amount`]: struct.PeekMoreIterator.html#method.peek_amount
+    pub fn peek_window_sum(&mut self, n: usize) -> I::Item
+    where
+        I::Item: Sum + Clone,
+    {
+        self.peek_window(n).cloned().sum()
+    }
+
+    /// Buffers the next `n` unconsumed elements and returns an iterator over the ones which exist,
+    /// i.e. the elements of the window up to the end of the underlying iterator.
+    fn peek_window(&mut self, n: usize) -> impl Iterator<Item = &I::Item> {
+        if n > 0 {
+            self.fill_queue(n - 1);
+        }
+
+        self.queue.iter().take(n).map_while(Option::as_ref)
+    }
+
+    /// Returns the overlapping windows of length `size` over all unconsumed elements, starting
+    /// with the window at the end and moving towards the front.
+    ///
+    /// This method buffers the remaining elements of the underlying iterator, and as such only works
+    /// for finite iterators: it will never return for an infinite iterator. With a lookahead limit,
+    /// buffering stops at the limit, and the windows end at the last element within the limit
+    /// rather than at the end of the iterator.
+    /// The windows are cloned out of the queue; no elements are consumed and the cursor is not moved.
+    ///
+    /// # Panics
+    ///
+    /// **Panics** if `size` is `0`.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3, 4].into_iter().peekmore();
+    ///
+    /// assert_eq!(iter.peek_rwindows(2), vec![vec![3, 4], vec![2, 3], vec![1, 2]]);
+    /// assert_eq!(iter.next(), Some(1));
+    /// ```
+    pub fn peek_rwindows(&mut self, size: usize) -> Vec<Vec<I::Item>>
+    where
+        I::Item: Clone,
+    {
+        assert!(size > 0, "window size should be greater than zero");
+
+        self.fill_queue_to_end();
+
+        let elements: Vec<&I::Item> = self.queue.iter().map_while(Option::as_ref).collect();
+        elements
+            .windows(size)
+            .rev()
+            .map(|window| window.iter().map(|&v| v.clone()).collect())
+            .collect()
+    }
+
+    /// Returns the index and the slice of the longest candidate which matches the unconsumed
+    /// elements at the front of the iterator, or `None` if no candidate matches.
+    ///
+    /// Enough elements are buffered to compare against the longest candidate. If several matching
+    /// candidates are equally long, the earliest one is returned. An empty candidate always matches.
+    /// No elements are consumed and the cursor is not moved.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "<<=".chars().peekmore();
+    /// let candidates: &[&[char]] = &[&['<'], &['<', '<'], &['<', '=']];
+    ///
+    /// assert_eq!(iter.match_prefix(candidates), Some((1, &['<', '<'][..])));
+    /// assert_eq!(iter.next(), Some('<'));
+    /// ```
+    pub fn match_prefix<'a, T>(&mut self, candidates: &'a [&'a [T]]) -> Option<(usize, &'a [T])>
+    where
+        I::Item: PartialEq<T>,
+    {
+        let longest = candidates.iter().map(|candidate| candidate.len()).max()?;
+        let peeked = self.peek_amount(longest);
+
+        let mut best: Option<(usize, &'a [T])> = None;
+        for (index, &candidate) in candidates.iter().enumerate() {
+            let matches = candidate.len() <= peeked.len()
+                && peeked
+                    .iter()
+                    .zip(candidate)
+                    .all(|(peeked, expected)| peeked.as_ref().is_some_and(|v| v == expected));
+
+            if matches && best.is_none_or(|(_, found)| candidate.len() > found.len()) {
+                best = Some((index, candidate));
+            }
+        }
+
+        best
+    }
+
+    /// Consumes and returns the unconsumed elements at the front of the iterator which match the
+    /// longest candidate, or consumes nothing and returns `None` if no candidate matches.
+    ///
+    /// This is the maximal munch rule of lexers: the candidate is chosen by [`match_prefix`] before
+    /// anything is consumed, so either the whole candidate is consumed or nothing is. The cursor
+    /// moves along with the consumed elements, as it does for [`next()`].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "<<=".chars().peekmore();
+    /// let candidates: &[&[char]] = &[&['<'], &['<', '<'], &['<', '<', '=']];
+    ///
+    /// assert_eq!(iter.consume_longest_prefix(candidates), Some(vec!['<', '<', '=']));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    ///
+    /// [`match_prefix`]: struct.PeekMoreIterator.html#method.match_prefix
+    /// [`next()`]: struct.PeekMoreIterator.html#impl-Iterator
+    pub fn consume_longest_prefix<T>(&mut self, candidates: &[&[T]]) -> Option<Vec<I::Item>>
+    where
+        I::Item: PartialEq<T>,
+    {
+        let (_, candidate) = self.match_prefix(candidates)?;
+
+        Some(self.by_ref().take(candidate.len()).collect())
+    }
+
+    /// Returns the index of the `close` element which matches the `open` element at the front of
+    /// the iterator, taking nesting into account, or `None` if the first unconsumed element isn't
+    /// `open` or if it's never closed.
+    ///
+    /// Elements are buffered one at a time, until the matching `close` element is found. If the
+    /// `open` element is never closed, the remaining elements of the underlying iterator are
+    /// buffered, so this method will never return `None` for an infinite iterator. The index is
+    /// relative to the first unconsumed element. No elements are consumed and the cursor is not
+    /// moved.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "(a(b))c".chars().peekmore();
+    ///
+    /// assert_eq!(iter.peek_matching(&'(', &')'), Some(5));
+    /// ```
+    pub fn peek_matching<T>(&mut self, open: &T, close: &T) -> Option<usize>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        let mut depth = 0usize;
+        let mut index = 0;
+
+        loop {
+            let element = self.peek_nth(index)?;
 
-        while current_pos < target_end {
-            let next_end = (current_pos + chunk_size).min(target_end);
+            if element == open {
+                depth += 1;
+            } else if depth == 0 {
+                return None;
+            } else if element == close {
+                depth -= 1;
 
-            // Fill this chunk
-            for _ in current_pos..next_end {
-                self.push_next_to_queue();
+                if depth == 0 {
+                    return Some(index);
+                }
             }
 
-            current_pos = next_end;
+            index += 1;
         }
     }
 
-    /// Returns a view into the next `n` unconsumed elements of the iterator.
-    ///
-    /// Here, `n` represents the amount of elements as counted from the start of the unconsumed iterator.
-    ///
-    /// For example, if we created a (peekmore) iterator from the array `[1, 2, 3]` and consume the first
-    /// element by calling the regular `Iterator::next` method, and then call `peek_amount(3)`, the iterator will
-    /// return `&[Some(2), Some(3), None]`. Here `Some(2)` and `Some(3)` are queued elements which
-    /// we can peek at, and are not consumed by the iterator yet. `None` is the last element returned by
-    /// our view, since our original iterator is sized and doesn't contain more elements. Thus in the absence
-    /// of additional elements, we return `None`. This method is a variation on [`peek_range`].
-    /// You could instead have called `peek_range(0, n)` (note that `peek_range` takes indices as arguments
-    /// instead of an amount).
+    /// Returns the index of the first unconsumed element which differs from the corresponding
+    /// element of `expected`, or `None` if the next `expected.len()` elements all match.
     ///
-    /// **Note:** This method does not use or modify the position of the cursor.
+    /// If the underlying iterator runs out of elements before the end of `expected`, the index at
+    /// which it ran out is returned. No elements are consumed and the cursor is not moved.
     ///
     /// # Example:
     ///
     /// ```
     /// use obsessive_peek::PeekMore;
     ///
-    /// let iterable = [1, 2, 3];
-    /// let mut iter = iterable.iter().peekmore();
+    /// let mut iter = [1, 2, 9].into_iter().peekmore();
     ///
-    /// match iter.peek_amount(4) { // -> &[Option(&1), Option(&2), Option(&3), None]
-    ///   [Some(a), Some(b), Some(c), None] => println!("Found a match ({}, {}, {}) ", a, b, c),
-    ///   _ => eprintln!("Expected (just) 3 more values"),
-    /// }
+    /// assert_eq!(iter.diff_with(&[1, 2, 3]), Some(2));
+    /// assert_eq!(iter.diff_with(&[1, 2]), None);
     /// ```
-    ///
-    /// [`peek_range`]: struct.PeekMoreIterator.html#method.peek_range
-    #[inline]
-    pub fn peek_amount(&mut self, n: usize) -> &[Option<I::Item>] {
-        self.peek_range(0, n)
+    pub fn diff_with<T>(&mut self, expected: &[T]) -> Option<usize>
+    where
+        I::Item: PartialEq<T>,
+    {
+        let peeked = self.peek_amount(expected.len());
+
+        expected.iter().enumerate().position(|(index, expected)| {
+            !peeked
+                .get(index)
+                .and_then(Option::as_ref)
+                .is_some_and(|v| v == expected)
+        })
     }
 
     /// Consumes and returns the next item of this iterator if a condition is true.
@@ -773,6 +3023,42 @@ impl<I: Iterator> PeekMoreIterator<I> {
         }
     }
 
+    /// Peeks at the next item of this iterator, and lets `f` decide whether to consume it.
+    ///
+    /// `f` receives the next item (or `None` if there is none), and returns a [`PeekDecision`]
+    /// which holds the value to return. If `f` returns [`PeekDecision::Consume`], the item is
+    /// consumed; with [`PeekDecision::Keep`], it is not. Like [`next_if`], this method is
+    /// independent of the cursor location.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::{PeekDecision, PeekMore};
+    ///
+    /// let mut iter = ['-', '1'].into_iter().peekmore();
+    ///
+    /// let negative = iter.peek_then(|c| match c {
+    ///     Some('-') => PeekDecision::Consume(true),
+    ///     _ => PeekDecision::Keep(false),
+    /// });
+    ///
+    /// assert!(negative);
+    /// assert_eq!(iter.next(), Some('1'));
+    /// ```
+    ///
+    /// [`PeekDecision`]: enum.PeekDecision.html
+    /// [`PeekDecision::Consume`]: enum.PeekDecision.html#variant.Consume
+    /// [`PeekDecision::Keep`]: enum.PeekDecision.html#variant.Keep
+    /// [`next_if`]: struct.PeekMoreIterator.html#method.next_if
+    pub fn peek_then<R>(&mut self, f: impl FnOnce(Option<&I::Item>) -> PeekDecision<R>) -> R {
+        match f(self.peek_first()) {
+            PeekDecision::Consume(value) => {
+                let _ = self.next();
+                value
+            }
+            PeekDecision::Keep(value) => value,
+        }
+    }
+
     /// Consumes and returns the next item if it is equal to `expected`.
     ///
     /// Uses [`next_eq`] underneath.
@@ -786,6 +3072,387 @@ impl<I: Iterator> PeekMoreIterator<I> {
     {
         self.next_if(|next| next == expected)
     }
+
+    /// Consumes and returns the next item if it is equal to any of the elements of `set`.
+    ///
+    /// Like [`next_if`], this method is independent of the cursor location.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "+1".chars().peekmore();
+    ///
+    /// assert_eq!(iter.next_if_any_of(&['+', '-']), Some('+'));
+    /// assert_eq!(iter.next_if_any_of(&['+', '-']), None);
+    /// assert_eq!(iter.next(), Some('1'));
+    /// ```
+    ///
+    /// [`next_if`]: struct.PeekMoreIterator.html#method.next_if
+    #[inline]
+    pub fn next_if_any_of<T>(&mut self, set: &[T]) -> Option<I::Item>
+    where
+        I::Item: PartialEq<T>,
+    {
+        self.next_if(|next| set.iter().any(|member| next == member))
+    }
+
+    /// Consumes and returns items for as long as `predicate` returns `true`.
+    ///
+    /// The predicate receives the zero-based index of the item within this consumption run, in
+    /// addition to the item itself. The first item for which `predicate` returns `false` is not
+    /// consumed. Like [`next_if`], this method is independent of the cursor location.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = (1..100).peekmore();
+    ///
+    /// // Consume at most 3 items.
+    /// assert_eq!(iter.consume_while_indexed(|i, _| i < 3), vec![1, 2, 3]);
+    /// assert_eq!(iter.next(), Some(4));
+    /// ```
+    ///
+    /// [`next_if`]: struct.PeekMoreIterator.html#method.next_if
+    pub fn consume_while_indexed<P: Fn(usize, &I::Item) -> bool>(
+        &mut self,
+        predicate: P,
+    ) -> Vec<I::Item> {
+        let mut consumed = Vec::new();
+
+        while let Some(item) = self.next_if(|v| predicate(consumed.len(), v)) {
+            consumed.push(item);
+        }
+
+        consumed
+    }
+
+    /// Applies `f` to the next item, and consumes it as long as `f` returns `Some`, collecting the
+    /// returned values, like a peeking [`Iterator::map_while`].
+    ///
+    /// The first item for which `f` returns `None` is not consumed. Like [`next_if`], this method
+    /// is independent of the cursor location.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "12a".chars().peekmore();
+    ///
+    /// assert_eq!(iter.map_while_peek(|c| c.to_digit(10)), vec![1, 2]);
+    /// assert_eq!(iter.next(), Some('a'));
+    /// ```
+    ///
+    /// [`Iterator::map_while`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.map_while
+    /// [`next_if`]: struct.PeekMoreIterator.html#method.next_if
+    pub fn map_while_peek<B, F: FnMut(&I::Item) -> Option<B>>(&mut self, mut f: F) -> Vec<B> {
+        let mut mapped = Vec::new();
+
+        while let Some(value) = self.peek_first().and_then(&mut f) {
+            let _ = self.next();
+            mapped.push(value);
+        }
+
+        mapped
+    }
+
+    /// Applies `f` to the unconsumed items, starting at the first one, and collects the returned
+    /// values for as long as `f` returns `Some`, like a peeking [`Iterator::map_while`].
+    ///
+    /// Unlike [`map_while_peek`], no items are consumed: the items are only buffered, up to and
+    /// including the first item for which `f` returns `None`. Like [`peek_nth`], this method neither
+    /// uses nor modifies the position of the cursor.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "12a".chars().peekmore();
+    ///
+    /// assert_eq!(iter.peek_take_while_map(|c| c.to_digit(10)), vec![1, 2]);
+    /// assert_eq!(iter.next(), Some('1'));
+    /// ```
+    ///
+    /// [`Iterator::map_while`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.map_while
+    /// [`map_while_peek`]: struct.PeekMoreIterator.html#method.map_while_peek
+    /// [`peek_nth`]: struct.PeekMoreIterator.html#method.peek_nth
+    pub fn peek_take_while_map<B, F: Fn(&I::Item) -> Option<B>>(&mut self, f: F) -> Vec<B> {
+        let mut mapped = Vec::new();
+
+        while let Some(value) = self.peek_nth(mapped.len()).and_then(&f) {
+            mapped.push(value);
+        }
+
+        mapped
+    }
+
+    /// Consumes items until `stop` returns `true` for the next item, and returns the consumed items
+    /// for which `keep` returns `true`. The other consumed items are dropped.
+    ///
+    /// The item for which `stop` returns `true` is not consumed. Like [`next_if`], this method is
+    /// independent of the cursor location.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = "a b ;c".chars().peekmore();
+    ///
+    /// let tokens = iter.consume_filtered(|c| !c.is_whitespace(), |&c| c == ';');
+    /// assert_eq!(tokens, vec!['a', 'b']);
+    /// assert_eq!(iter.next(), Some(';'));
+    /// ```
+    ///
+    /// [`next_if`]: struct.PeekMoreIterator.html#method.next_if
+    pub fn consume_filtered<P, Q>(&mut self, keep: P, stop: Q) -> Vec<I::Item>
+    where
+        P: Fn(&I::Item) -> bool,
+        Q: Fn(&I::Item) -> bool,
+    {
+        let mut kept = Vec::new();
+
+        while let Some(item) = self.next_if(|v| !stop(v)) {
+            if keep(&item) {
+                kept.push(item);
+            }
+        }
+
+        kept
+    }
+
+    /// Turns this iterator into an iterator over groups of consecutive elements which are separated
+    /// by delimiters, as determined by `is_delim`. The delimiters themselves are dropped.
+    ///
+    /// Like [`str::split_terminator`], a delimiter at the very end does not produce a trailing empty
+    /// group, but consecutive delimiters do produce empty groups in between.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let groups: Vec<Vec<i32>> = [1, 2, 0, 3, 0, 4]
+    ///     .into_iter()
+    ///     .peekmore()
+    ///     .split_on(|&x| x == 0)
+    ///     .collect();
+    ///
+    /// assert_eq!(groups, vec![vec![1, 2], vec![3], vec![4]]);
+    /// ```
+    ///
+    /// [`str::split_terminator`]: https://doc.rust-lang.org/core/primitive.str.html#method.split_terminator
+    pub fn split_on<P: Fn(&I::Item) -> bool>(
+        mut self,
+        is_delim: P,
+    ) -> impl Iterator<Item = Vec<I::Item>> {
+        core::iter::from_fn(move || {
+            self.peek_first()?;
+
+            let mut group = Vec::new();
+            while let Some(item) = self.next_if(|v| !is_delim(v)) {
+                group.push(item);
+            }
+
+            // skip the delimiter which ended the group (if any)
+            let _ = self.next();
+
+            Some(group)
+        })
+    }
+
+    /// Turns this iterator into a multi-peek iterator over its first `n` unconsumed elements, like
+    /// [`Iterator::take`], but without losing the ability to peek.
+    ///
+    /// Already buffered elements are kept (up to the `n`-th), as are the cursor position and the
+    /// amount of consumed elements; the remaining elements are taken from the underlying iterator.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = (1..10).peekmore().take_peekmore(3);
+    ///
+    /// assert_eq!(iter.peek_nth(2), Some(&3));
+    /// assert_eq!(iter.peek_nth(3), None);
+    /// ```
+    ///
+    /// [`Iterator::take`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.take
+    pub fn take_peekmore(mut self, n: usize) -> PeekMoreIterator<Take<I>> {
+        // elements buffered at the back can't be part of the new underlying iterator, so buffer the
+        // `n` elements which are taken at the front instead
+        let limit = self.limit.take();
+        if !self.back_queue.is_empty() && n > 0 {
+            self.fill_queue(n - 1);
+        }
+
+        self.queue.truncate(n);
+        let remaining = n - self.buffered_some_len();
+
+        PeekMoreIterator {
+            iterator: self.iterator.take(remaining),
+            queue: self.queue,
+            cursor: self.cursor,
+            limit,
+            growth: self.growth,
+            back_queue: Vec::new(),
+            consumed: self.consumed,
+        }
+    }
+
+    /// Turns this iterator into a multi-peek iterator over its unconsumed elements followed by the
+    /// elements of `other`, like [`Iterator::chain`], but without losing the ability to peek.
+    ///
+    /// Already buffered elements are kept, as are the cursor position and the amount of consumed
+    /// elements. If the end of this iterator was buffered, peeking continues into `other`.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2].into_iter().peekmore();
+    /// assert_eq!(iter.peek_nth(2), None);
+    ///
+    /// let mut chained = iter.chain_peekmore([3, 4]);
+    /// assert_eq!(chained.peek_nth(2), Some(&3));
+    /// ```
+    ///
+    /// [`Iterator::chain`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html#method.chain
+    pub fn chain_peekmore<U>(mut self, other: U) -> PeekMoreIterator<Chain<I, U::IntoIter>>
+    where
+        U: IntoIterator<Item = I::Item>,
+    {
+        // elements buffered at the back have to come before the elements of `other`, so buffer
+        // this iterator completely at the front instead
+        let limit = self.limit.take();
+        if !self.back_queue.is_empty() {
+            self.fill_queue_to_end();
+        }
+
+        // drop the `None`s which mark the end of this iterator (there may be several, if the queue
+        // was padded by `peek_range`), so peeking continues into `other`
+        self.queue.truncate(self.buffered_some_len());
+
+        PeekMoreIterator {
+            iterator: self.iterator.chain(other),
+            queue: self.queue,
+            cursor: self.cursor,
+            limit,
+            growth: self.growth,
+            back_queue: Vec::new(),
+            consumed: self.consumed,
+        }
+    }
+
+    /// Turns this iterator into an iterator which can only peek at its next element, like
+    /// [`core::iter::Peekable`], for use with code written against that interface.
+    ///
+    /// Elements which were already buffered are kept, and are yielded first. The cursor is reset.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore();
+    /// iter.peek_nth(1);
+    ///
+    /// let mut peekable = iter.into_peekable();
+    /// assert_eq!(peekable.peek(), Some(&1));
+    /// assert_eq!(peekable.next_if_eq(&1), Some(1));
+    /// assert_eq!(peekable.peek(), Some(&2));
+    /// ```
+    ///
+    /// [`core::iter::Peekable`]: https://doc.rust-lang.org/core/iter/struct.Peekable.html
+    pub fn into_peekable(self) -> SinglePeekable<I> {
+        SinglePeekable::new(self)
+    }
+
+    /// Turns this iterator into an iterator over runs of consecutive equal elements.
+    ///
+    /// Each run is yielded as a `Vec` holding the equal elements, in order. The end of a run is
+    /// found by peeking at the element after it, so no element is taken from the underlying
+    /// iterator before it's needed.
+    ///
+    /// # Example:
+    /// ```
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let runs: Vec<Vec<i32>> = [1, 1, 2, 3, 3].into_iter().peekmore().group_runs().collect();
+    ///
+    /// assert_eq!(runs, vec![vec![1, 1], vec![2], vec![3, 3]]);
+    /// ```
+    pub fn group_runs(mut self) -> impl Iterator<Item = Vec<I::Item>>
+    where
+        I::Item: PartialEq,
+    {
+        core::iter::from_fn(move || {
+            let mut group = Vec::new();
+            group.push(self.next()?);
+
+            while let Some(item) = self.next_if(|v| *v == group[0]) {
+                group.push(item);
+            }
+
+            Some(group)
+        })
+    }
+}
+
+impl<'a, T> PeekMoreIterator<core::slice::Iter<'a, T>> {
+    /// Create a multi-peek iterator over the elements of a slice, which yields references to the
+    /// elements. This is equivalent to `s.iter().peekmore()`.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMoreIterator;
+    ///
+    /// let mut iter = PeekMoreIterator::from_slice(&[1, 2, 3]);
+    ///
+    /// assert_eq!(iter.peek_nth(2), Some(&&3));
+    /// assert_eq!(iter.next(), Some(&1));
+    /// ```
+    pub fn from_slice(s: &'a [T]) -> Self {
+        s.iter().peekmore()
+    }
+}
+
+impl<I: DoubleEndedIterator> PeekMoreIterator<I> {
+    /// Get a reference to the `n`-th element counted from the end of the iterator, where the last
+    /// element is the `0`-th. If no such element exists, `None` will be returned.
+    ///
+    /// Elements are taken from the back of the underlying iterator, and buffered separately from
+    /// the elements taken from the front, so the iterator doesn't have to be buffered completely.
+    /// Once the front and the back meet, the elements which were buffered at the front are used.
+    /// No elements are consumed and the cursor is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = (1..=5).peekmore();
+    ///
+    /// assert_eq!(iter.peek_nth_back(0), Some(&5));
+    /// assert_eq!(iter.peek_nth_back(1), Some(&4));
+    /// assert_eq!(iter.peek(), Some(&1));
+    ///
+    /// assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn peek_nth_back(&mut self, n: usize) -> Option<&I::Item> {
+        while self.back_queue.len() <= n {
+            match self.iterator.next_back() {
+                Some(item) => self.back_queue.push(item),
+                None => break,
+            }
+        }
+
+        if n < self.back_queue.len() {
+            return self.back_queue.get(n);
+        }
+
+        // the underlying iterator ran out of elements, so the remaining elements are buffered at
+        // the front
+        let from_front = n - self.back_queue.len();
+        let index = self.buffered_some_len().checked_sub(from_front + 1)?;
+
+        self.queue[index].as_ref()
+    }
 }
 
 impl<I: Iterator> Iterator for PeekMoreIterator<I> {
@@ -793,15 +3460,84 @@ impl<I: Iterator> Iterator for PeekMoreIterator<I> {
 
     fn next(&mut self) -> Option<Self::Item> {
         let res = if self.queue.is_empty() {
-            self.iterator.next()
+            self.next_from_iterator()
         } else {
             self.queue.remove(0)
         };
 
+        if res.is_some() {
+            self.consumed += 1;
+        }
+
+        // removing the front of the queue can't queue an element after the end
         self.decrement_cursor();
+        self.debug_assert_invariants_from(self.queue.len());
 
         res
     }
+
+    // Drops the skipped buffered elements in one go, instead of dequeueing them one by one with
+    // `next()`, which would shift the remaining queue for every skipped element.
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let buffered = self.buffered_some_len();
+
+        if n < buffered {
+            self.consumed += self.queue.drain(0..n).count();
+            self.cursor = self.cursor.saturating_sub(n);
+            return self.next();
+        }
+
+        let exhausted = buffered < self.queue.len();
+        let mut skipped = buffered;
+        self.queue.clear();
+
+        let mut item = None;
+        if !exhausted {
+            // skip the remaining elements one by one (rather than with `Iterator::nth`), to know how
+            // many were consumed if the underlying iterator runs out of elements
+            for _ in buffered..=n {
+                item = self.next_from_iterator();
+                if item.is_none() {
+                    break;
+                }
+                skipped += 1;
+            }
+        }
+
+        // the cursor moves back by the amount of elements which were actually consumed
+        self.consumed += skipped;
+        self.cursor = self.cursor.saturating_sub(skipped);
+
+        item
+    }
+
+    fn count(self) -> usize {
+        let buffered = self.buffered_some_len();
+
+        if buffered < self.queue.len() {
+            // the end of the underlying iterator has been buffered already
+            buffered
+        } else {
+            buffered + self.iterator.count() + self.back_queue.len()
+        }
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        let buffered = self.buffered_some_len();
+        let exhausted = buffered < self.queue.len();
+
+        self.queue.truncate(buffered);
+        let last_buffered = self.queue.pop().flatten();
+
+        if exhausted {
+            last_buffered
+        } else if !self.back_queue.is_empty() {
+            // the first element taken from the back is the last element
+            Some(self.back_queue.swap_remove(0))
+        } else {
+            self.iterator.last().or(last_buffered)
+        }
+    }
 }
 
 /// Uses [`ExactSizeIterator`] default implementation.
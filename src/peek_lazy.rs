@@ -0,0 +1,40 @@
+use crate::peek_iterator::PeekMoreIterator;
+
+/// Walks over the unconsumed elements of a [`PeekMoreIterator`], starting at the front, and
+/// buffers them one at a time, as the walk proceeds.
+///
+/// This can't be an [`Iterator`]: buffering another element may reallocate the queue, which would
+/// invalidate references to elements which were yielded earlier. Instead, each reference returned
+/// by [`next_peek`] borrows the walk until the next call.
+///
+/// Created with [`PeekMoreIterator::peeks`].
+///
+/// [`PeekMoreIterator`]: struct.PeekMoreIterator.html
+/// [`Iterator`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html
+/// [`next_peek`]: struct.Peeks.html#method.next_peek
+/// [`PeekMoreIterator::peeks`]: struct.PeekMoreIterator.html#method.peeks
+pub struct Peeks<'a, I: Iterator> {
+    inner: &'a mut PeekMoreIterator<I>,
+    index: usize,
+}
+
+impl<'a, I: Iterator> Peeks<'a, I> {
+    pub(crate) fn new(inner: &'a mut PeekMoreIterator<I>) -> Self {
+        Peeks { inner, index: 0 }
+    }
+
+    /// Buffer the next element of the walk, and return a reference to it, or `None` once the end
+    /// of the iterator is reached.
+    pub fn next_peek(&mut self) -> Option<&I::Item> {
+        let element = self.inner.peek_nth(self.index)?;
+        self.index += 1;
+
+        Some(element)
+    }
+
+    /// Return the amount of elements the walk has yielded so far.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.index
+    }
+}
@@ -0,0 +1,39 @@
+use crate::peek_iterator::PeekMoreIterator;
+
+/// Merge two multi-peek iterators into a single iterator, by repeatedly comparing their first
+/// unconsumed elements.
+///
+/// If `less(a, b)` returns `true`, the element of `a` is yielded first; otherwise the element of
+/// `b` is. To prefer the elements of `a` when two elements are equal, use a comparison such as
+/// `a <= b`. If both iterators are sorted according to `less`, the merged iterator is sorted too.
+/// Once one of the iterators runs out of elements, the remaining elements of the other are yielded.
+///
+/// ```rust
+/// use obsessive_peek::{PeekMore, merge_by};
+///
+/// let a = [1, 3, 5].into_iter().peekmore();
+/// let b = [2, 4, 6].into_iter().peekmore();
+///
+/// let merged: Vec<i32> = merge_by(a, b, |a, b| a < b).collect();
+/// assert_eq!(merged, vec![1, 2, 3, 4, 5, 6]);
+/// ```
+pub fn merge_by<A, B, F>(
+    mut a: PeekMoreIterator<A>,
+    mut b: PeekMoreIterator<B>,
+    less: F,
+) -> impl Iterator<Item = A::Item>
+where
+    A: Iterator,
+    B: Iterator<Item = A::Item>,
+    F: Fn(&A::Item, &B::Item) -> bool,
+{
+    core::iter::from_fn(move || {
+        let take_a = match (a.peek_first(), b.peek_first()) {
+            (Some(x), Some(y)) => less(x, y),
+            (Some(_), None) => true,
+            (None, _) => false,
+        };
+
+        if take_a { a.next() } else { b.next() }
+    })
+}
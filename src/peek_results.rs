@@ -0,0 +1,63 @@
+/// Use a `Vec` to collect references to peeked elements
+use alloc::vec::Vec;
+
+use crate::peek_iterator::PeekMoreIterator;
+
+/// Convenience methods for iterators which yield `Result` elements.
+impl<T, E, I: Iterator<Item = Result<T, E>>> PeekMoreIterator<I> {
+    /// Peek at the next `n` unconsumed elements, and return references to the leading `Ok` values,
+    /// together with the first `Err` within those `n` elements (if any).
+    ///
+    /// Elements after the first `Err` are not inspected. No elements are consumed and the cursor
+    /// is not moved.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [Ok(1), Ok(2), Err("x"), Ok(3)].into_iter().peekmore();
+    ///
+    /// let (oks, err) = iter.peek_oks(4);
+    /// assert_eq!(oks, vec![&1, &2]);
+    /// assert_eq!(err, Some(&"x"));
+    /// ```
+    pub fn peek_oks(&mut self, n: usize) -> (Vec<&T>, Option<&E>) {
+        let mut oks = Vec::new();
+
+        for element in self.peek_amount(n).iter().map_while(Option::as_ref) {
+            match element {
+                Ok(value) => oks.push(value),
+                Err(error) => return (oks, Some(error)),
+            }
+        }
+
+        (oks, None)
+    }
+
+    /// Peek at the element the cursor points to, and surface an `Err` element as an error.
+    ///
+    /// Returns a reference to the `Ok` value, `Ok(None)` if no element exists, or a clone of the
+    /// error if the element is an `Err`. This makes it possible to use `?` on peeked elements in
+    /// functions which return a `Result`. The element is not consumed.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// fn first_is_even(iter: &mut impl Iterator<Item = Result<i32, String>>) -> Result<bool, String> {
+    ///     let mut iter = iter.peekmore();
+    ///     Ok(iter.try_peek()?.is_some_and(|v| v % 2 == 0))
+    /// }
+    ///
+    /// assert_eq!(first_is_even(&mut vec![Ok(2)].into_iter()), Ok(true));
+    /// assert_eq!(first_is_even(&mut vec![Err("bad".to_string())].into_iter()), Err("bad".to_string()));
+    /// ```
+    pub fn try_peek<'a>(&'a mut self) -> Result<Option<&'a T>, E>
+    where
+        E: Clone + 'a,
+    {
+        match self.peek() {
+            Some(Ok(value)) => Ok(Some(value)),
+            Some(Err(error)) => Err(error.clone()),
+            None => Ok(None),
+        }
+    }
+}
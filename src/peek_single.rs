@@ -0,0 +1,111 @@
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::peek_iterator::PeekMoreIterator;
+
+/// An iterator which can only peek at its next element, like [`core::iter::Peekable`], backed by
+/// a [`PeekMoreIterator`].
+///
+/// Elements which were already buffered by the multi-peek iterator are peeked at and consumed
+/// first, before any further elements are taken from the underlying iterator. The cursor of the
+/// multi-peek iterator is not used.
+///
+/// This iterator can be created with [`PeekMoreIterator::into_peekable`].
+///
+/// [`core::iter::Peekable`]: https://doc.rust-lang.org/core/iter/struct.Peekable.html
+/// [`PeekMoreIterator`]: struct.PeekMoreIterator.html
+/// [`PeekMoreIterator::into_peekable`]: struct.PeekMoreIterator.html#method.into_peekable
+pub struct SinglePeekable<I: Iterator> {
+    inner: PeekMoreIterator<I>,
+}
+
+// Implemented by hand, since deriving would only require `I` (and not `I::Item`) to implement the
+// trait.
+impl<I: Iterator + Clone> Clone for SinglePeekable<I>
+where
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        SinglePeekable {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<I: Iterator + fmt::Debug> fmt::Debug for SinglePeekable<I>
+where
+    I::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SinglePeekable")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<I: Iterator> SinglePeekable<I> {
+    pub(crate) fn new(mut inner: PeekMoreIterator<I>) -> Self {
+        inner.reset_cursor();
+        SinglePeekable { inner }
+    }
+
+    /// Get a reference to the next element, without consuming it, like
+    /// [`core::iter::Peekable::peek`].
+    ///
+    /// [`core::iter::Peekable::peek`]: https://doc.rust-lang.org/core/iter/struct.Peekable.html#method.peek
+    #[inline]
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.inner.peek_first()
+    }
+
+    /// Get a mutable reference to the next element, without consuming it, like
+    /// [`core::iter::Peekable::peek_mut`].
+    ///
+    /// [`core::iter::Peekable::peek_mut`]: https://doc.rust-lang.org/core/iter/struct.Peekable.html#method.peek_mut
+    #[inline]
+    pub fn peek_mut(&mut self) -> Option<&mut I::Item> {
+        self.inner.peek_first();
+        self.inner.queue.first_mut().and_then(Option::as_mut)
+    }
+
+    /// Consume and return the next element if `func` returns `true` for it, like
+    /// [`core::iter::Peekable::next_if`].
+    ///
+    /// [`core::iter::Peekable::next_if`]: https://doc.rust-lang.org/core/iter/struct.Peekable.html#method.next_if
+    #[inline]
+    pub fn next_if(&mut self, func: impl FnOnce(&I::Item) -> bool) -> Option<I::Item> {
+        self.inner.next_if(func)
+    }
+
+    /// Consume and return the next element if it is equal to `expected`, like
+    /// [`core::iter::Peekable::next_if_eq`].
+    ///
+    /// [`core::iter::Peekable::next_if_eq`]: https://doc.rust-lang.org/core/iter/struct.Peekable.html#method.next_if_eq
+    #[inline]
+    pub fn next_if_eq<T>(&mut self, expected: &T) -> Option<I::Item>
+    where
+        T: ?Sized,
+        I::Item: PartialEq<T>,
+    {
+        self.inner.next_if_eq(expected)
+    }
+
+    /// Turn this iterator back into the multi-peek iterator which backs it.
+    pub fn into_inner(self) -> PeekMoreIterator<I> {
+        self.inner
+    }
+}
+
+impl<I: Iterator> Iterator for SinglePeekable<I> {
+    type Item = I::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+}
+
+/// Uses [`FusedIterator`] default implementation.
+///
+/// [`FusedIterator`]: https://doc.rust-lang.org/core/iter/trait.FusedIterator.html
+impl<I: FusedIterator> FusedIterator for SinglePeekable<I> {}
@@ -1,3 +1,5 @@
+use core::fmt;
+
 /// This enumeration provides errors which represent lack of success of the [`PeekMoreIterator`].
 ///
 /// [`PeekMoreIterator`]: struct.PeekMoreIterator.html
@@ -7,4 +9,45 @@ pub enum PeekMoreError {
     /// consumed by the iterator.
     /// We can only peek at elements which haven't been consumed.
     ElementHasBeenConsumed,
+    /// This error case will be returned if peeking at an element would require buffering more
+    /// elements than a fixed capacity queue can hold.
+    CapacityExceeded,
+    /// This error case will be returned if peeking at an element would require buffering more
+    /// elements than the lookahead limit of the iterator allows.
+    LookaheadExceeded,
+    /// This error case will be returned if the cursor would be moved beyond the last element of
+    /// the iterator.
+    CursorOutOfBounds,
+    /// This error case will be returned if a range of elements is requested where the start of the
+    /// range lies beyond its end. The offending bounds are included.
+    InvalidRange {
+        /// The (inclusive) start of the requested range.
+        start: usize,
+        /// The (exclusive) end of the requested range.
+        end: usize,
+    },
+}
+
+impl fmt::Display for PeekMoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PeekMoreError::ElementHasBeenConsumed => {
+                f.write_str("the element has already been consumed")
+            }
+            PeekMoreError::CapacityExceeded => {
+                f.write_str("the capacity of the fixed size queue was exceeded")
+            }
+            PeekMoreError::LookaheadExceeded => f.write_str("the lookahead limit was exceeded"),
+            PeekMoreError::CursorOutOfBounds => {
+                f.write_str("the cursor would move beyond the last element")
+            }
+            PeekMoreError::InvalidRange { start, end } => {
+                write!(
+                    f,
+                    "invalid range: start {} is greater than end {}",
+                    start, end
+                )
+            }
+        }
+    }
 }
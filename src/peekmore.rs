@@ -1,6 +1,12 @@
 /// Use a `Vec` to queue iterator elements
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+#[cfg(feature = "alloc")]
+use crate::peek_builder::{GrowthStrategy, PeekMoreBuilder};
+#[cfg(feature = "heapless")]
+use crate::peek_heapless::HeaplessPeekMoreIterator;
+#[cfg(feature = "alloc")]
 use crate::peek_iterator::PeekMoreIterator;
 
 /// Trait which allows you to create the multi-peek iterator.
@@ -11,15 +17,134 @@ use crate::peek_iterator::PeekMoreIterator;
 /// [`Iterator`]: https://doc.rust-lang.org/core/iter/trait.Iterator.html
 pub trait PeekMore: Iterator + Sized {
     /// Create a multi-peek iterator where we can peek forward multiple times from an existing iterator.
+    #[cfg(feature = "alloc")]
     fn peekmore(self) -> PeekMoreIterator<Self>;
+
+    /// Create a multi-peek iterator which buffers at most `max` elements ahead.
+    ///
+    /// Peeking beyond the limit doesn't buffer any further elements: methods such as [`peek_nth`]
+    /// return `None` instead, while [`try_peek_nth`] and [`try_advance_cursor_by`] return a
+    /// [`PeekMoreError::LookaheadExceeded`]. This guards against accidentally buffering huge amounts
+    /// of elements, for example of an infinite iterator.
+    ///
+    /// ```rust
+    /// use obsessive_peek::{PeekMore, PeekMoreError};
+    ///
+    /// let mut iter = (0..).peekmore_limited(2);
+    ///
+    /// assert_eq!(iter.peek_nth(1), Some(&1));
+    /// assert_eq!(iter.peek_nth(2), None);
+    /// assert_eq!(iter.try_peek_nth(2), Err(PeekMoreError::LookaheadExceeded));
+    ///
+    /// // consuming an element makes room for the next one
+    /// assert_eq!(iter.next(), Some(0));
+    /// assert_eq!(iter.try_peek_nth(1), Ok(Some(&2)));
+    /// ```
+    ///
+    /// [`peek_nth`]: struct.PeekMoreIterator.html#method.peek_nth
+    /// [`try_peek_nth`]: struct.PeekMoreIterator.html#method.try_peek_nth
+    /// [`try_advance_cursor_by`]: struct.PeekMoreIterator.html#method.try_advance_cursor_by
+    /// [`PeekMoreError::LookaheadExceeded`]: enum.PeekMoreError.html#variant.LookaheadExceeded
+    #[cfg(feature = "alloc")]
+    fn peekmore_limited(self, max: usize) -> PeekMoreIterator<Self> {
+        let mut iter = self.peekmore();
+        iter.limit = Some(max);
+        iter
+    }
+
+    /// Create a [`PeekMoreBuilder`], which configures the multi-peek iterator before it is created.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = [1, 2, 3].into_iter().peekmore_builder().capacity(3).build();
+    ///
+    /// assert_eq!(iter.peek_nth(2), Some(&3));
+    /// ```
+    ///
+    /// [`PeekMoreBuilder`]: struct.PeekMoreBuilder.html
+    #[cfg(feature = "alloc")]
+    fn peekmore_builder(self) -> PeekMoreBuilder<Self> {
+        PeekMoreBuilder::new(self)
+    }
+
+    /// Create a multi-peek iterator which borrows the existing iterator instead of taking ownership
+    /// of it.
+    ///
+    /// Once the multi-peek iterator is dropped, the original iterator can be used again. Note that
+    /// elements which were peeked at, but not consumed, have already been taken from the original
+    /// iterator, and are dropped together with the multi-peek iterator.
+    ///
+    /// ```rust
+    /// use obsessive_peek::PeekMore;
+    ///
+    /// let mut iter = vec![1, 2, 3].into_iter();
+    ///
+    /// let mut peeking = iter.peekmore_by_ref();
+    /// assert_eq!(peeking.peek(), Some(&1));
+    /// assert_eq!(peeking.next(), Some(1));
+    ///
+    /// assert_eq!(iter.next(), Some(2));
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn peekmore_by_ref(&mut self) -> PeekMoreIterator<&mut Self> {
+        self.peekmore()
+    }
+
+    /// Create a multi-peek iterator which buffers at most `N` elements in a fixed capacity queue,
+    /// so no allocations are made for the queue.
+    ///
+    /// Only available with the `heapless` feature. This is the only way to create a multi-peek
+    /// iterator without the (default) `alloc` feature.
+    #[cfg(feature = "heapless")]
+    fn peekmore_heapless<const N: usize>(self) -> HeaplessPeekMoreIterator<Self, N>;
 }
 
 impl<I: Iterator> PeekMore for I {
+    #[cfg(feature = "alloc")]
     fn peekmore(self) -> PeekMoreIterator<I> {
         PeekMoreIterator {
             iterator: self,
             queue: Vec::new(),
             cursor: 0usize,
+            limit: None,
+            growth: GrowthStrategy::Amortized,
+            back_queue: Vec::new(),
+            consumed: 0usize,
+        }
+    }
+
+    #[cfg(feature = "heapless")]
+    fn peekmore_heapless<const N: usize>(self) -> HeaplessPeekMoreIterator<I, N> {
+        HeaplessPeekMoreIterator {
+            iterator: self,
+            queue: heapless::Vec::new(),
+            cursor: 0usize,
         }
     }
 }
+
+/// Create a multi-peek iterator from a closure, which is called to produce each next element,
+/// like [`core::iter::from_fn`].
+///
+/// ```rust
+/// use obsessive_peek::peekmore_from_fn;
+///
+/// let mut count = 0;
+/// let mut iter = peekmore_from_fn(move || {
+///     count += 1;
+///     (count <= 3).then_some(count)
+/// });
+///
+/// assert_eq!(iter.peek_nth(2), Some(&3));
+/// assert_eq!(iter.peek_nth(3), None);
+/// assert_eq!(iter.next(), Some(1));
+/// ```
+///
+/// [`core::iter::from_fn`]: https://doc.rust-lang.org/core/iter/fn.from_fn.html
+#[cfg(feature = "alloc")]
+pub fn peekmore_from_fn<T>(
+    f: impl FnMut() -> Option<T>,
+) -> PeekMoreIterator<impl Iterator<Item = T>> {
+    core::iter::from_fn(f).peekmore()
+}
@@ -0,0 +1,197 @@
+#![cfg(feature = "alloc")]
+
+use obsessive_peek::{PeekMore, merge_by};
+
+#[test]
+fn split_on_drops_delimiters() {
+    let groups: Vec<Vec<i32>> = [1, 2, 0, 3, 0, 4]
+        .into_iter()
+        .peekmore()
+        .split_on(|&x| x == 0)
+        .collect();
+
+    assert_eq!(groups, vec![vec![1, 2], vec![3], vec![4]]);
+}
+
+#[test]
+fn split_on_leading_trailing_and_repeated_delimiters() {
+    let groups: Vec<Vec<i32>> = [0, 1, 0, 0, 2, 0]
+        .into_iter()
+        .peekmore()
+        .split_on(|&x| x == 0)
+        .collect();
+
+    assert_eq!(groups, vec![vec![], vec![1], vec![], vec![2]]);
+
+    let mut empty = core::iter::empty::<i32>().peekmore().split_on(|&x| x == 0);
+    assert_eq!(empty.next(), None);
+}
+
+#[test]
+fn merge_by_interleaves_sorted_iterators() {
+    let a = [1, 3, 5].into_iter().peekmore();
+    let b = [2, 4, 6].into_iter().peekmore();
+
+    let merged: Vec<i32> = merge_by(a, b, |a, b| a < b).collect();
+    assert_eq!(merged, vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn merge_by_uneven_and_ties() {
+    let a = [(1, 'a'), (2, 'a')].into_iter().peekmore();
+    let b = [(1, 'b'), (3, 'b'), (4, 'b')].into_iter().peekmore();
+
+    // prefer `a` on ties
+    let merged: Vec<(i32, char)> = merge_by(a, b, |a, b| a.0 <= b.0).collect();
+    assert_eq!(
+        merged,
+        vec![(1, 'a'), (1, 'b'), (2, 'a'), (3, 'b'), (4, 'b')]
+    );
+
+    let a = core::iter::empty::<i32>().peekmore();
+    let b = [1, 2].into_iter().peekmore();
+    let merged: Vec<i32> = merge_by(a, b, |a, b| a < b).collect();
+    assert_eq!(merged, vec![1, 2]);
+}
+
+#[test]
+fn group_runs_consecutive_equal() {
+    let runs: Vec<Vec<i32>> = [1, 1, 2, 3, 3]
+        .into_iter()
+        .peekmore()
+        .group_runs()
+        .collect();
+
+    assert_eq!(runs, vec![vec![1, 1], vec![2], vec![3, 3]]);
+}
+
+#[test]
+fn group_runs_non_adjacent_and_empty() {
+    let runs: Vec<Vec<char>> = "aaba".chars().peekmore().group_runs().collect();
+    assert_eq!(runs, vec![vec!['a', 'a'], vec!['b'], vec!['a']]);
+
+    let mut empty = core::iter::empty::<i32>().peekmore().group_runs();
+    assert_eq!(empty.next(), None);
+}
+
+#[test]
+fn into_peekable_single_step() {
+    let mut iter = [1, 2, 3, 4].into_iter().peekmore();
+
+    // buffer elements and move the cursor before downgrading
+    assert_eq!(iter.peek_nth(2), Some(&3));
+    iter.advance_cursor_by(2);
+
+    let mut peekable = iter.into_peekable();
+    assert_eq!(peekable.peek(), Some(&1));
+    assert_eq!(peekable.peek(), Some(&1));
+    assert_eq!(peekable.next(), Some(1));
+
+    if let Some(value) = peekable.peek_mut() {
+        *value = 20;
+    }
+    assert_eq!(peekable.next_if(|&v| v > 10), Some(20));
+    assert_eq!(peekable.next_if_eq(&4), None);
+    assert_eq!(peekable.next(), Some(3));
+
+    // beyond the buffered elements
+    assert_eq!(peekable.peek(), Some(&4));
+    assert_eq!(peekable.next(), Some(4));
+    assert_eq!(peekable.peek(), None);
+    assert_eq!(peekable.next(), None);
+}
+
+#[test]
+fn into_peekable_into_inner() {
+    let mut peekable = (1..5).peekmore().into_peekable();
+    assert_eq!(peekable.next(), Some(1));
+
+    let mut iter = peekable.into_inner();
+    assert_eq!(iter.peek_nth(2), Some(&4));
+}
+
+#[test]
+fn take_peekmore_bounded_prefix() {
+    let mut iter = (1..10).peekmore().take_peekmore(3);
+
+    assert_eq!(iter.peek_nth(2), Some(&3));
+    assert_eq!(iter.peek_nth(3), None);
+    assert_eq!(iter.peek_nth(8), None);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn take_peekmore_keeps_buffer() {
+    let mut iter = (1..10).peekmore();
+    assert_eq!(iter.next(), Some(1));
+    iter.peek_nth(4);
+    iter.advance_cursor();
+
+    let mut taken = iter.take_peekmore(2);
+    assert_eq!(taken.cursor(), 1);
+    assert_eq!(taken.consumed(), 1);
+    assert_eq!(taken.peek(), Some(&3));
+    assert_eq!(taken.peek_nth(2), None);
+    assert_eq!(taken.collect::<Vec<_>>(), vec![2, 3]);
+
+    let mut short = (1..3).peekmore();
+    short.peek_nth(5);
+    assert_eq!(short.take_peekmore(4).collect::<Vec<_>>(), vec![1, 2]);
+}
+
+#[test]
+fn take_peekmore_with_back_buffer() {
+    let mut iter = (1..=4).peekmore();
+    assert_eq!(iter.peek_nth_back(1), Some(&3));
+
+    let taken = iter.clone().take_peekmore(3);
+    assert_eq!(taken.collect::<Vec<_>>(), vec![1, 2, 3]);
+
+    let taken = iter.take_peekmore(6);
+    assert_eq!(taken.collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn chain_peekmore_across_boundary() {
+    let mut chained = [1, 2].into_iter().peekmore().chain_peekmore([3, 4]);
+
+    assert_eq!(
+        chained.peek_amount(4),
+        &[Some(1), Some(2), Some(3), Some(4)]
+    );
+    assert_eq!(chained.peek_nth(4), None);
+    assert_eq!(chained.collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn chain_peekmore_splices_buffer() {
+    let mut iter = [1, 2].into_iter().peekmore();
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.peek_nth(3), None);
+    iter.advance_cursor();
+
+    let mut chained = iter.chain_peekmore(vec![3, 4]);
+    assert_eq!(chained.cursor(), 1);
+    assert_eq!(chained.consumed(), 1);
+    assert_eq!(chained.peek(), Some(&3));
+    assert_eq!(chained.collect::<Vec<_>>(), vec![2, 3, 4]);
+}
+
+#[test]
+fn chain_peekmore_after_padded_range() {
+    let mut iter = [1, 2].into_iter().peekmore();
+    assert_eq!(iter.peek_range(0, 10).len(), 10);
+
+    let mut chained = iter.chain_peekmore([3, 4]);
+    assert_eq!(chained.peek_nth(2), Some(&3));
+    assert_eq!(chained.collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn chain_peekmore_with_back_buffer() {
+    let mut iter = (1..=3).peekmore();
+    assert_eq!(iter.peek_nth_back(0), Some(&3));
+
+    let chained = iter.chain_peekmore([4]);
+    assert_eq!(chained.collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+}
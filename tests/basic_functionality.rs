@@ -1,4 +1,6 @@
-use obsessive_peek::PeekMore;
+#![cfg(feature = "alloc")]
+
+use obsessive_peek::{PeekMore, PeekMoreIterator, peekmore_from_fn};
 
 #[test]
 fn readme_example() {
@@ -56,3 +58,176 @@ fn test_with_inherited_feature_count() {
     let count = iter.count();
     assert_eq!(count, 2);
 }
+
+#[test]
+fn peekmore_by_ref_returns_borrowed_iterator() {
+    let mut iter = vec![1, 2, 3, 4].into_iter();
+
+    {
+        let mut peeking = iter.peekmore_by_ref();
+        assert_eq!(peeking.peek(), Some(&1));
+        assert_eq!(peeking.peek_nth(1), Some(&2));
+        assert_eq!(peeking.next(), Some(1));
+    }
+
+    // the second element was buffered by the dropped peekmore iterator
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn debug_state_summary() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+    iter.peek_nth(2);
+    iter.advance_cursor();
+
+    let state = iter.debug_state();
+    assert!(state.contains("cursor: 1"));
+    assert!(state.contains("queue length: 3"));
+    assert!(state.contains("[Some(1), Some(2), Some(3)]"));
+}
+
+#[test]
+fn debug_assert_invariants_valid_states() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+    iter.debug_assert_invariants();
+
+    iter.peek_nth(1);
+    iter.advance_cursor_by(10);
+    iter.debug_assert_invariants();
+
+    // padded with `None`s past the end
+    let _ = iter.peek_range(0, 6);
+    iter.debug_assert_invariants();
+
+    iter.insert_peeked(1, 99);
+    assert_eq!(iter.remove_peeked(0), Some(1));
+    iter.next();
+    iter.debug_assert_invariants();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "after the end of the underlying iterator")]
+fn debug_assert_invariants_corrupted_queue() {
+    let mut iter = [1, 2].into_iter().peekmore();
+    iter.peek_nth(3);
+
+    iter.queue.push(Some(3));
+    iter.debug_assert_invariants();
+}
+
+#[test]
+fn debug_assert_invariants_limited_insert() {
+    let mut iter = [1, 2].into_iter().peekmore_limited(2);
+    iter.peek_nth(1);
+
+    iter.insert_peeked(0, 0);
+    assert_eq!(iter.buffered_len(), 3);
+    iter.debug_assert_invariants();
+
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2]);
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "padded beyond the lookahead limit")]
+fn debug_assert_invariants_padding_beyond_limit() {
+    let mut iter = [1].into_iter().peekmore_limited(2);
+    iter.peek_nth(1);
+
+    iter.queue.extend([None, None]);
+    iter.debug_assert_invariants();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "padded beyond the lookahead limit")]
+fn debug_assert_invariants_checked_by_next() {
+    let mut iter = [1, 2].into_iter().peekmore_limited(2);
+    iter.peek_nth(1);
+
+    iter.queue.extend([None, None, None]);
+    iter.next();
+}
+
+#[test]
+fn buffered_lengths() {
+    let mut iter = [1, 2, 3, 4].into_iter().peekmore();
+    assert_eq!(iter.buffered_len(), 0);
+    assert_eq!(iter.buffered_some_len(), 0);
+
+    iter.peek_nth(2);
+    assert_eq!(iter.buffered_len(), 3);
+    assert_eq!(iter.buffered_some_len(), 3);
+
+    // the None marking the end is only counted by buffered_len
+    iter.peek_nth(4);
+    assert_eq!(iter.buffered_len(), 5);
+    assert_eq!(iter.buffered_some_len(), 4);
+}
+
+#[test]
+fn peekmore_from_fn_counter() {
+    let mut counter = 0;
+    let mut iter = peekmore_from_fn(move || {
+        counter += 1;
+        Some(counter)
+    });
+
+    assert_eq!(iter.peek_nth(4), Some(&5));
+    assert_eq!(iter.peek_amount(3), &[Some(1), Some(2), Some(3)]);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.peek(), Some(&2));
+}
+
+#[test]
+fn from_slice_peeks_and_consumes() {
+    let values: &[i32] = &[10, 20, 30];
+    let mut iter = PeekMoreIterator::from_slice(values);
+
+    assert_eq!(iter.peek(), Some(&&10));
+    assert_eq!(iter.peek_nth(2), Some(&&30));
+    assert_eq!(iter.next(), Some(&10));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![&20, &30]);
+}
+
+#[test]
+fn try_clone_buffered_independent_consumption() {
+    // `from_fn` iterators aren't `Clone`
+    let mut values = vec![1, 2, 3].into_iter();
+    let mut iter = peekmore_from_fn(move || values.next());
+
+    assert!(iter.try_clone_buffered().is_none());
+    assert_eq!(iter.peek_nth(2), Some(&3));
+    assert!(iter.try_clone_buffered().is_none());
+
+    assert_eq!(iter.peek_nth(3), None);
+    iter.advance_cursor();
+    let mut copy = iter.try_clone_buffered().unwrap();
+
+    assert_eq!(copy.peek(), Some(&2));
+    assert_eq!(copy.next(), Some(1));
+    assert_eq!(copy.next(), Some(2));
+
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.consumed(), 1);
+    assert_eq!(copy.consumed(), 2);
+
+    assert_eq!(copy.collect::<Vec<_>>(), vec![3]);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3]);
+}
+
+#[test]
+fn fill_to_buffers_without_peeking() {
+    let mut iter = (0..1000).peekmore();
+
+    iter.fill_to(3);
+    assert!(iter.buffered_len() >= 4);
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(&iter.buffered()[..4], &[Some(0), Some(1), Some(2), Some(3)]);
+
+    let mut limited = (0..1000).peekmore_limited(2);
+    assert_eq!(limited.fill_to(3).buffered_len(), 2);
+}
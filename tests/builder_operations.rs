@@ -0,0 +1,45 @@
+#![cfg(feature = "alloc")]
+
+use obsessive_peek::{GrowthStrategy, PeekMore, PeekMoreError};
+
+#[test]
+fn builder_defaults_match_peekmore() {
+    let mut iter = [1, 2, 3].into_iter().peekmore_builder().build();
+
+    assert_eq!(iter.lookahead_limit(), None);
+    assert_eq!(iter.peek_nth(2), Some(&3));
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn builder_capacity() {
+    let iter = (0..100).peekmore_builder().capacity(32).build();
+
+    assert!(iter.queue.capacity() >= 32);
+    assert!(iter.buffered().is_empty());
+}
+
+#[test]
+fn builder_limit() {
+    let mut iter = (0..).peekmore_builder().capacity(4).limit(3).build();
+
+    assert_eq!(iter.lookahead_limit(), Some(3));
+    assert_eq!(iter.peek_nth(2), Some(&2));
+    assert_eq!(iter.try_peek_nth(3), Err(PeekMoreError::LookaheadExceeded));
+}
+
+#[test]
+fn builder_linear_growth() {
+    let mut iter = (0..)
+        .peekmore_builder()
+        .capacity(4)
+        .growth(GrowthStrategy::Linear(4))
+        .build();
+
+    iter.peek_nth(4);
+    assert_eq!(iter.queue.capacity(), 8);
+
+    iter.peek_nth(8);
+    assert_eq!(iter.queue.capacity(), 12);
+    assert_eq!(iter.peek_nth(8), Some(&8));
+}
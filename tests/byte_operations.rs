@@ -0,0 +1,16 @@
+#![cfg(feature = "alloc")]
+
+use obsessive_peek::PeekMore;
+
+#[test]
+fn peek_bytes_header() {
+    let data: &[u8] = &[0xCA, 0xFE, 0xBA, 0xBE, 0x00, 0x01];
+    let mut iter = data.iter().copied().peekmore();
+
+    assert_eq!(iter.peek_bytes(4), vec![0xCA, 0xFE, 0xBA, 0xBE]);
+
+    // nothing was consumed
+    assert_eq!(iter.next(), Some(0xCA));
+    assert_eq!(iter.peek_bytes(10), vec![0xFE, 0xBA, 0xBE, 0x00, 0x01]);
+    assert!(iter.peek_bytes(0).is_empty());
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "alloc")]
+
+use obsessive_peek::PeekMore;
+
+#[test]
+fn peek_str_len_matches_front() {
+    let mut iter = "let x".chars().peekmore();
+
+    assert!(iter.peek_str_len("let"));
+    assert!(iter.peek_str_len("let x"));
+    assert!(iter.peek_str_len(""));
+    assert!(!iter.peek_str_len("fn"));
+    assert!(!iter.peek_str_len("let x = 1"));
+
+    // nothing was consumed
+    assert_eq!(iter.next(), Some('l'));
+    assert!(iter.peek_str_len("et"));
+}
+
+#[test]
+fn peek_str_len_multi_byte_chars() {
+    let mut iter = "héllo wörld".chars().peekmore();
+
+    assert!(iter.peek_str_len("hé"));
+    assert!(!iter.peek_str_len("he"));
+}
+
+#[test]
+fn peek_string_does_not_consume() {
+    let mut iter = "hello".chars().peekmore();
+
+    assert_eq!(iter.peek_string(3), "hel");
+    assert_eq!(iter.peek_string(0), "");
+    assert_eq!(iter.peek_string(10), "hello");
+
+    assert_eq!(iter.next(), Some('h'));
+    assert_eq!(iter.peek_string(2), "el");
+}
+
+#[test]
+fn peek_str_len_beyond_lookahead_limit() {
+    let mut iter = "let x".chars().peekmore_limited(2);
+
+    assert!(iter.peek_str_len("le"));
+    assert!(!iter.peek_str_len("let"));
+}
+
+#[test]
+fn consume_string_consumes_chars() {
+    let mut iter = "hello".chars().peekmore();
+
+    assert_eq!(iter.consume_string(3), "hel");
+    assert_eq!(iter.next(), Some('l'));
+    assert_eq!(iter.consume_string(10), "o");
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn consume_string_fixes_cursor() {
+    let mut iter = "hello".chars().peekmore();
+
+    iter.advance_cursor_by(4);
+    assert_eq!(iter.peek(), Some(&'o'));
+
+    assert_eq!(iter.consume_string(2), "he");
+    assert_eq!(iter.cursor(), 2);
+    assert_eq!(iter.peek(), Some(&'o'));
+}
+
+#[test]
+fn peek_number_len_decimal() {
+    let mut iter = "123.4abc".chars().peekmore();
+    assert_eq!(iter.peek_number_len(), 5);
+    assert_eq!(iter.next(), Some('1'));
+
+    let mut negative = "-7x".chars().peekmore();
+    assert_eq!(negative.peek_number_len(), 2);
+}
+
+#[test]
+fn peek_number_len_edge_cases() {
+    for (input, expected) in [
+        ("", 0),
+        ("abc", 0),
+        ("-", 0),
+        ("-x", 0),
+        (".5", 0),
+        ("7", 1),
+        ("7.", 1),
+        ("7.x", 1),
+        ("1.2.3", 3),
+        ("--1", 0),
+    ] {
+        assert_eq!(
+            input.chars().peekmore().peek_number_len(),
+            expected,
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn consume_number_leading_run() {
+    let mut iter = "42 rest".chars().peekmore();
+
+    assert_eq!(iter.consume_number(), Some("42".to_string()));
+    assert_eq!(iter.next(), Some(' '));
+
+    // not a number: nothing is consumed
+    assert_eq!(iter.consume_number(), None);
+    assert_eq!(iter.next(), Some('r'));
+
+    let mut fraction = "-0.25;".chars().peekmore();
+    assert_eq!(fraction.consume_number(), Some("-0.25".to_string()));
+    assert_eq!(fraction.next(), Some(';'));
+}
+
+#[test]
+fn whitespace_helpers() {
+    let mut iter = "   x".chars().peekmore();
+
+    assert_eq!(iter.peek_whitespace_len(), 3);
+    assert_eq!(iter.peek(), Some(&' '));
+
+    assert_eq!(iter.skip_whitespace(), 3);
+    assert_eq!(iter.peek_whitespace_len(), 0);
+    assert_eq!(iter.skip_whitespace(), 0);
+    assert_eq!(iter.next(), Some('x'));
+
+    let mut blank = " \t\n".chars().peekmore();
+    assert_eq!(blank.peek_whitespace_len(), 3);
+    assert_eq!(blank.skip_whitespace(), 3);
+    assert_eq!(blank.next(), None);
+}
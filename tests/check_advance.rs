@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use obsessive_peek::PeekMore;
 
 #[test]
@@ -1,4 +1,6 @@
-use obsessive_peek::PeekMore;
+#![cfg(feature = "alloc")]
+
+use obsessive_peek::{PeekDecision, PeekMore};
 
 #[test]
 fn test_with_consume() {
@@ -280,3 +282,360 @@ fn next_if_eq_works() {
 
     assert_eq!(iter.next_if_eq(&&5), None);
 }
+
+#[test]
+fn split_at_cursor_after_advance() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    iter.advance_cursor_by(2);
+    let (prefix, mut rest) = iter.split_at_cursor();
+
+    assert_eq!(prefix, vec![&1, &2]);
+    assert_eq!(rest.cursor(), 0);
+    assert_eq!(rest.peek(), Some(&&3));
+    assert_eq!(rest.next(), Some(&3));
+    assert_eq!(rest.next(), Some(&4));
+    assert_eq!(rest.next(), None);
+}
+
+#[test]
+fn split_at_cursor_without_advance() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_nth(2), Some(&&3));
+    let (prefix, mut rest) = iter.split_at_cursor();
+
+    assert!(prefix.is_empty());
+    assert_eq!(rest.next(), Some(&1));
+}
+
+#[test]
+fn split_at_cursor_beyond_end() {
+    let iterable = [1, 2];
+    let mut iter = iterable.iter().peekmore();
+
+    iter.advance_cursor_by(5);
+    let (prefix, mut rest) = iter.split_at_cursor();
+
+    assert_eq!(prefix, vec![&1, &2]);
+    assert_eq!(rest.next(), None);
+}
+
+#[test]
+fn split_at_cursor_beyond_lookahead_limit() {
+    let mut iter = (1..10).peekmore_limited(2);
+
+    iter.advance_cursor_by(5);
+    let (prefix, mut rest) = iter.split_at_cursor();
+
+    assert_eq!(prefix, vec![1, 2, 3, 4, 5]);
+    assert_eq!(rest.consumed(), 5);
+    assert_eq!(rest.lookahead_limit(), Some(2));
+    assert_eq!(rest.peek(), Some(&6));
+    assert_eq!(rest.next(), Some(6));
+}
+
+#[test]
+fn truncate_queue_at_returns_dropped_elements() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    let _ = iter.peek_amount(4);
+    let dropped = iter.truncate_queue_at(2);
+    assert_eq!(dropped, vec![&3, &4]);
+    assert_eq!(iter.queue.len(), 2);
+
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn truncate_queue_at_beyond_queue_is_noop() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    let _ = iter.peek_nth(1);
+    assert!(iter.truncate_queue_at(5).is_empty());
+    assert!(iter.truncate_queue_at(2).is_empty());
+
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+}
+
+#[test]
+fn consume_while_indexed_bounded_by_index() {
+    let mut iter = (1..100).peekmore();
+
+    assert_eq!(iter.consume_while_indexed(|i, _| i < 3), vec![1, 2, 3]);
+    assert_eq!(iter.next(), Some(4));
+}
+
+#[test]
+fn consume_while_indexed_position_dependent() {
+    let mut iter = [0, 2, 4, 7, 8].iter().peekmore();
+
+    // each element should be twice its index
+    let consumed = iter.consume_while_indexed(|i, &&v| v == 2 * i as i32);
+    assert_eq!(consumed, vec![&0, &2, &4]);
+    assert_eq!(iter.next(), Some(&7));
+
+    let mut empty = core::iter::empty::<i32>().peekmore();
+    assert!(empty.consume_while_indexed(|_, _| true).is_empty());
+}
+
+#[test]
+fn dedup_buffered_by_key_compacts_queue() {
+    let iterable = [("a", 1), ("a", 2), ("b", 3)];
+    let mut iter = iterable.iter().peekmore();
+
+    let _ = iter.peek_amount(3);
+    iter.dedup_buffered_by_key(|&&(name, _)| name);
+
+    assert_eq!(iter.next(), Some(&("a", 1)));
+    assert_eq!(iter.next(), Some(&("b", 3)));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn dedup_buffered_by_key_fixes_cursor() {
+    let iterable = [("a", 1), ("a", 2), ("b", 3), ("b", 4), ("c", 5)];
+    let mut iter = iterable.iter().peekmore();
+
+    // the cursor points at ("c", 5), and keeps pointing at it
+    iter.advance_cursor_by(4);
+    assert_eq!(iter.peek(), Some(&&("c", 5)));
+    iter.dedup_buffered_by_key(|&&(name, _)| name);
+    assert_eq!(iter.cursor(), 2);
+    assert_eq!(iter.peek(), Some(&&("c", 5)));
+
+    // the cursor pointed at a removed element, and moves to the first element of its run
+    let mut iter = iterable.iter().peekmore();
+    iter.advance_cursor_by(3);
+    assert_eq!(iter.peek(), Some(&&("b", 4)));
+    iter.dedup_buffered_by_key(|&&(name, _)| name);
+    assert_eq!(iter.peek(), Some(&&("b", 3)));
+}
+
+#[test]
+fn dedup_buffered_by_key_only_touches_buffer() {
+    let iterable = [1, 1, 1, 1];
+    let mut iter = iterable.iter().peekmore();
+
+    let _ = iter.peek_nth(1);
+    iter.dedup_buffered_by_key(|&&v| v);
+
+    assert_eq!(iter.queue.len(), 1);
+    assert_eq!(iter.by_ref().count(), 3);
+}
+
+#[test]
+fn consume_longest_prefix_maximal_munch() {
+    let mut iter = "==>x".chars().peekmore();
+    let candidates: &[&[char]] = &[&['='], &['=', '='], &['=', '=', '>']];
+
+    assert_eq!(
+        iter.consume_longest_prefix(candidates),
+        Some(vec!['=', '=', '>'])
+    );
+    assert_eq!(iter.next(), Some('x'));
+}
+
+#[test]
+fn consume_longest_prefix_no_match_consumes_nothing() {
+    let mut iter = [1, 2, 4].into_iter().peekmore();
+    let candidates: &[&[i32]] = &[&[1, 2, 3], &[2]];
+
+    assert_eq!(iter.consume_longest_prefix(candidates), None);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+}
+
+#[test]
+fn take_peeked_consumes_buffer_only() {
+    let mut iter = (1..10).peekmore();
+
+    assert_eq!(iter.peek_nth(2), Some(&3));
+    iter.advance_cursor_by(2);
+
+    let taken: Vec<i32> = iter.take_peeked().collect();
+    assert_eq!(taken, vec![1, 2, 3]);
+    assert_eq!(iter.cursor(), 0);
+    assert!(iter.buffered().is_empty());
+    assert_eq!(iter.next(), Some(4));
+}
+
+#[test]
+fn take_peeked_nothing_buffered() {
+    let mut iter = (1..10).peekmore();
+
+    assert_eq!(iter.take_peeked().next(), None);
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn clear_buffer_returns_buffered_elements() {
+    let mut iter = (1..10).peekmore();
+
+    assert_eq!(iter.peek_nth(3), Some(&4));
+    iter.advance_cursor_by(2);
+
+    assert_eq!(iter.clear_buffer(), vec![1, 2, 3, 4]);
+    assert_eq!(iter.cursor(), 0);
+    assert!(iter.buffered().is_empty());
+
+    // continues with the inner iterator
+    assert_eq!(iter.next(), Some(5));
+    assert!(iter.clear_buffer().is_empty());
+}
+
+#[test]
+fn peek_then_consumes_or_keeps() {
+    let mut iter = [2, 3, 4].into_iter().peekmore();
+
+    let decide = |v: Option<&i32>| match v {
+        Some(&v) if v % 2 == 0 => PeekDecision::Consume(Some(v)),
+        _ => PeekDecision::Keep(None),
+    };
+
+    assert_eq!(iter.peek_then(decide), Some(2));
+    assert_eq!(iter.peek_then(decide), None);
+    assert_eq!(iter.peek(), Some(&3));
+    assert_eq!(iter.next(), Some(3));
+
+    assert_eq!(iter.peek_then(decide), Some(4));
+    assert_eq!(iter.peek_then(decide), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn peek_decision_variants() {
+    let consume = PeekDecision::Consume('a');
+    let keep = PeekDecision::Keep('b');
+    assert_ne!(consume, PeekDecision::Keep('a'));
+
+    let mut iter = [1, 2].into_iter().peekmore();
+
+    assert_eq!(iter.peek_then(|_| keep), 'b');
+    assert_eq!(iter.peek(), Some(&1));
+
+    assert_eq!(iter.peek_then(|_| consume), 'a');
+    assert_eq!(iter.peek(), Some(&2));
+}
+
+#[test]
+fn replace_peeked_element() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+
+    assert_eq!(iter.replace_peeked(1, 99), Some(2));
+    assert_eq!(iter.replace_peeked(3, 100), None);
+    assert_eq!(iter.replace_peeked(5, 100), None);
+    assert_eq!(iter.peek_nth(3), None);
+
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(99));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn insert_peeked_element() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+
+    iter.insert_peeked(1, 99);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 99, 2, 3]);
+}
+
+#[test]
+fn insert_peeked_adjusts_cursor() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+    iter.advance_cursor_by(2);
+
+    iter.insert_peeked(0, 0);
+    assert_eq!(iter.cursor(), 3);
+    assert_eq!(iter.peek(), Some(&3));
+
+    iter.insert_peeked(4, 4);
+    assert_eq!(iter.cursor(), 3);
+    assert_eq!(iter.peek(), Some(&3));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn insert_peeked_beyond_end() {
+    let mut iter = [1, 2].into_iter().peekmore();
+    assert_eq!(iter.peek_nth(4), None);
+
+    iter.insert_peeked(10, 3);
+    assert_eq!(iter.peek_nth(2), Some(&3));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3]);
+}
+
+#[test]
+fn remove_peeked_element() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+
+    assert_eq!(iter.remove_peeked(1), Some(2));
+    assert_eq!(iter.remove_peeked(2), None);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 3]);
+}
+
+#[test]
+fn remove_peeked_adjusts_cursor() {
+    let mut iter = [1, 2, 3, 4].into_iter().peekmore();
+    iter.advance_cursor_by(2);
+
+    assert_eq!(iter.remove_peeked(0), Some(1));
+    assert_eq!(iter.cursor(), 1);
+    assert_eq!(iter.peek(), Some(&3));
+
+    assert_eq!(iter.remove_peeked(1), Some(3));
+    assert_eq!(iter.cursor(), 1);
+    assert_eq!(iter.peek(), Some(&4));
+
+    assert_eq!(iter.collect::<Vec<_>>(), vec![2, 4]);
+}
+
+#[test]
+fn next_if_any_of_character_class() {
+    let mut iter = ['a', 'b', 'c'].into_iter().peekmore();
+
+    assert_eq!(iter.next_if_any_of(&['x']), None);
+    assert_eq!(iter.peek(), Some(&'a'));
+
+    assert_eq!(iter.next_if_any_of(&['a']), Some('a'));
+
+    // independent of the cursor
+    iter.advance_cursor();
+    assert_eq!(iter.next_if_any_of(&['c']), None);
+    assert_eq!(iter.next_if_any_of(&['c', 'b']), Some('b'));
+    assert_eq!(iter.next(), Some('c'));
+}
+
+#[test]
+fn consume_filtered_until_stop() {
+    let mut iter = "1 2;3".chars().peekmore();
+
+    let tokens = iter.consume_filtered(|c| *c != ' ', |&c| c == ';');
+    assert_eq!(tokens, vec!['1', '2']);
+    assert_eq!(iter.next(), Some(';'));
+
+    // without a stop element, everything is consumed
+    assert_eq!(iter.consume_filtered(|_| true, |_| false), vec!['3']);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn map_while_peek_non_negative() {
+    let mut iter = [1, 2, 3, -1, 4].into_iter().peekmore();
+
+    let mapped = iter.map_while_peek(|&v| u32::try_from(v).ok());
+    assert_eq!(mapped, vec![1, 2, 3]);
+    assert_eq!(iter.next(), Some(-1));
+
+    assert_eq!(iter.map_while_peek(|&v| Some(v * 10)), vec![40]);
+    assert_eq!(iter.next(), None);
+}
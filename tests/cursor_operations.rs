@@ -1,4 +1,6 @@
-use obsessive_peek::PeekMore;
+#![cfg(feature = "alloc")]
+
+use obsessive_peek::{PeekMore, PeekMoreError};
 
 #[test]
 fn check_advance_separately() {
@@ -292,3 +294,320 @@ fn check_move_nth_empty() {
     iter.move_nth(10);
     assert_eq!(iter.cursor(), 10);
 }
+
+#[test]
+fn check_cursor_distance_to_end() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.cursor_distance_to_end(), 4);
+
+    iter.advance_cursor();
+    assert_eq!(iter.cursor_distance_to_end(), 3);
+
+    // the cursor is not moved and nothing is consumed
+    assert_eq!(iter.cursor(), 1);
+    assert_eq!(iter.peek(), Some(&&2));
+
+    iter.advance_cursor_by(5);
+    assert_eq!(iter.cursor_distance_to_end(), 0);
+}
+
+#[test]
+fn check_cursor_distance_to_end_after_consume() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    let _ = iter.next();
+    assert_eq!(iter.cursor_distance_to_end(), 3);
+
+    let empty: [i32; 0] = [];
+    let mut iter = empty.iter().peekmore();
+    assert_eq!(iter.cursor_distance_to_end(), 0);
+}
+
+#[test]
+fn check_advance_cursor_while_indexed_capped() {
+    let mut iter = (1..100).peekmore();
+
+    iter.advance_cursor_while_indexed(|i, _| i < 3);
+    assert_eq!(iter.cursor(), 3);
+    assert_eq!(iter.peek(), Some(&4));
+}
+
+#[test]
+fn check_advance_cursor_while_indexed_from_advanced_cursor() {
+    let iterable = [1, 2, 3, 4, 5];
+    let mut iter = iterable.iter().peekmore();
+
+    iter.advance_cursor();
+    iter.advance_cursor_while_indexed(|i, v| i < 4 && v.is_some_and(|&&v| v % 2 == 0));
+    assert_eq!(iter.cursor(), 2);
+
+    iter.advance_cursor_while_indexed(|_, v| v.is_some());
+    assert_eq!(iter.cursor(), 5);
+    assert_eq!(iter.peek(), None);
+}
+
+#[test]
+fn check_move_forward_while_long_run_does_not_overflow() {
+    let mut iter = (0..1_000_000).peekmore();
+
+    iter.advance_cursor_while(|v| v.is_some());
+    assert_eq!(iter.cursor(), 1_000_000);
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.next(), Some(0));
+}
+
+#[test]
+fn check_reset_and_shrink() {
+    let mut iter = (0..10_000).peekmore();
+
+    let _ = iter.peek_nth(5_000);
+    for _ in 0..4_998 {
+        let _ = iter.next();
+    }
+    iter.advance_cursor_by(2);
+    let capacity_before = iter.queue.capacity();
+
+    iter.reset_and_shrink();
+
+    assert_eq!(iter.cursor(), 0);
+    assert!(iter.queue.capacity() < capacity_before);
+    assert_eq!(iter.buffered(), &[Some(4_998), Some(4_999), Some(5_000)]);
+    assert_eq!(iter.next(), Some(4_998));
+}
+
+#[test]
+fn check_move_cursor_back_saturating() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    iter.advance_cursor_by(2);
+    assert_eq!(iter.move_cursor_back_saturating(5), 2);
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.peek(), Some(&&1));
+
+    iter.advance_cursor_by(3);
+    assert_eq!(iter.move_cursor_back_saturating(1), 1);
+    assert_eq!(iter.cursor(), 2);
+    assert_eq!(iter.peek(), Some(&&3));
+
+    iter.reset_cursor();
+    assert_eq!(iter.move_cursor_back_saturating(1), 0);
+    assert_eq!(iter.cursor(), 0);
+}
+
+#[test]
+fn advance_cursor_to_fills_queue() {
+    let mut iter = [1, 2, 3, 4, 5].into_iter().peekmore();
+
+    iter.advance_cursor_to(3);
+    assert_eq!(iter.cursor(), 3);
+    assert_eq!(iter.buffered(), &[Some(1), Some(2), Some(3), Some(4)]);
+    assert_eq!(iter.peek(), Some(&4));
+
+    // moving back doesn't drop buffered elements
+    iter.advance_cursor_to(1);
+    assert_eq!(iter.buffered().len(), 4);
+    assert_eq!(iter.peek(), Some(&2));
+}
+
+#[test]
+fn advance_cursor_to_beyond_end() {
+    let mut iter = [1, 2].into_iter().peekmore();
+
+    iter.advance_cursor_to(5);
+    assert_eq!(iter.cursor(), 5);
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn peek_forward_clamped_stops_at_last_element() {
+    let mut iter = [1, 2, 3].iter().peekmore();
+
+    assert_eq!(iter.peek_forward_clamped(1), Some(&&2));
+    assert_eq!(iter.cursor(), 1);
+
+    assert_eq!(iter.peek_forward_clamped(10), Some(&&3));
+    assert_eq!(iter.cursor(), 2);
+
+    assert_eq!(iter.peek_forward_clamped(1), Some(&&3));
+    assert_eq!(iter.cursor(), 2);
+}
+
+#[test]
+fn peek_forward_clamped_empty() {
+    let mut iter = core::iter::empty::<i32>().peekmore();
+
+    assert_eq!(iter.peek_forward_clamped(3), None);
+    assert_eq!(iter.cursor(), 0);
+}
+
+#[test]
+fn reset_and_shrink_after_rewind() {
+    let mut iter = (0..100).peekmore_builder().capacity(64).build();
+    iter.advance_cursor_to(3);
+
+    let capacity = iter.queue.capacity();
+    iter.reset_and_shrink();
+
+    assert_eq!(iter.cursor(), 0);
+    assert!(iter.queue.capacity() < capacity);
+    assert_eq!(iter.buffered(), &[Some(0), Some(1), Some(2), Some(3)]);
+    assert_eq!(iter.next(), Some(0));
+}
+
+#[test]
+fn advance_cursor_by_checked_reports_end() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+
+    assert_eq!(iter.advance_cursor_by_checked(5), (3, true));
+    assert_eq!(iter.cursor(), 5);
+
+    iter.reset_cursor();
+    assert_eq!(iter.advance_cursor_by_checked(2), (2, false));
+    assert_eq!(iter.peek(), Some(&3));
+    assert_eq!(iter.advance_cursor_by_checked(0), (0, false));
+    assert_eq!(iter.advance_cursor_by_checked(1), (1, true));
+    assert_eq!(iter.advance_cursor_by_checked(1), (0, true));
+}
+
+#[test]
+fn try_advance_cursor_reports_end() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+
+    assert_eq!(
+        iter.try_advance_cursor(5).err(),
+        Some(PeekMoreError::CursorOutOfBounds)
+    );
+    assert_eq!(iter.cursor_distance_to_end(), 3);
+    assert_eq!(iter.cursor(), 0);
+
+    assert!(iter.try_advance_cursor(2).is_ok());
+    assert_eq!(iter.peek(), Some(&3));
+    assert_eq!(iter.cursor_distance_to_end(), 1);
+}
+
+#[test]
+fn try_advance_cursor_within_bounds() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+
+    assert_eq!(
+        iter.try_advance_cursor(5).err(),
+        Some(PeekMoreError::CursorOutOfBounds)
+    );
+    assert_eq!(iter.cursor(), 0);
+
+    assert!(iter.try_advance_cursor(0).is_ok());
+    assert_eq!(iter.try_advance_cursor(1).map(|it| it.cursor()), Ok(1));
+    assert_eq!(
+        iter.try_advance_cursor(3).err(),
+        Some(PeekMoreError::CursorOutOfBounds)
+    );
+    assert_eq!(iter.try_advance_cursor(2).map(|it| it.cursor()), Ok(3));
+    assert_eq!(iter.peek(), None);
+}
+
+#[test]
+fn try_advance_cursor_lookahead_limit() {
+    let mut iter = (0..).peekmore_limited(2);
+
+    assert_eq!(
+        iter.try_advance_cursor(3).err(),
+        Some(PeekMoreError::LookaheadExceeded)
+    );
+    assert_eq!(iter.try_advance_cursor(2).map(|it| it.cursor()), Ok(2));
+}
+
+#[test]
+fn move_nth_checked_out_of_range() {
+    let mut iter = [1, 2, 3, 4].into_iter().peekmore();
+
+    assert_eq!(
+        iter.move_nth_checked(20).err(),
+        Some(PeekMoreError::CursorOutOfBounds)
+    );
+    assert_eq!(iter.cursor(), 0);
+
+    // the unchecked variant moves regardless
+    iter.move_nth(20);
+    assert_eq!(iter.cursor(), 20);
+    assert_eq!(iter.peek(), None);
+
+    assert_eq!(iter.move_nth_checked(3).map(|it| it.cursor()), Ok(3));
+    assert_eq!(iter.peek(), Some(&4));
+}
+
+#[test]
+fn move_nth_checked_lookahead_limit() {
+    let mut iter = (0..).peekmore_limited(2);
+
+    assert_eq!(
+        iter.move_nth_checked(2).err(),
+        Some(PeekMoreError::LookaheadExceeded)
+    );
+    assert_eq!(iter.move_nth_checked(1).map(|it| it.cursor()), Ok(1));
+}
+
+#[test]
+fn advance_cursor_until_change_anchors_to_initial() {
+    let mut iter = [3, 3, 3, 4, 3].into_iter().peekmore();
+
+    iter.advance_cursor_until_change();
+    assert_eq!(iter.cursor(), 3);
+    assert_eq!(iter.peek(), Some(&4));
+
+    iter.advance_cursor_until_change();
+    assert_eq!(iter.cursor(), 4);
+
+    // the run lasts until the end
+    iter.advance_cursor_until_change();
+    assert_eq!(iter.cursor(), 5);
+    assert_eq!(iter.peek(), None);
+
+    // no element at the cursor
+    iter.advance_cursor_until_change();
+    assert_eq!(iter.cursor(), 5);
+    assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+#[allow(deprecated)]
+fn compat_aliases_match_canonical_methods() {
+    let mut canonical = [1, 2, 3, 4, 5].into_iter().peekmore();
+    let mut alias = [1, 2, 3, 4, 5].into_iter().peekmore();
+
+    canonical.advance_cursor();
+    alias.advance_view();
+    assert_eq!(alias.cursor(), canonical.cursor());
+    assert_eq!(alias.peek(), canonical.peek());
+
+    canonical.advance_cursor_by(3);
+    alias.advance_view_by(3);
+    assert_eq!(alias.peek(), canonical.peek());
+
+    assert!(canonical.move_cursor_back().is_ok());
+    assert!(alias.move_backward().is_ok());
+    assert_eq!(alias.peek(), canonical.peek());
+
+    assert!(canonical.move_cursor_back_by(2).is_ok());
+    assert!(alias.move_backward_by(2).is_ok());
+    assert_eq!(alias.cursor(), canonical.cursor());
+    assert_eq!(
+        alias.move_backward_by(10).err(),
+        canonical.move_cursor_back_by(10).err()
+    );
+
+    canonical.move_cursor_back_or_reset(10);
+    alias.move_backward_or_reset(10);
+    assert_eq!(alias.cursor(), 0);
+    assert_eq!(alias.cursor(), canonical.cursor());
+
+    canonical.advance_cursor();
+    alias.advance_view();
+    canonical.reset_cursor();
+    alias.reset_view();
+    assert_eq!(alias.peek(), canonical.peek());
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 //! Tests for divide and conquer optimizations in PeekMoreIterator
 
 use obsessive_peek::PeekMore;
@@ -132,3 +134,59 @@ fn test_zero_operations() {
     let range = iter.peek_range(0, 0);
     assert_eq!(range.len(), 0);
 }
+
+#[test]
+fn test_peek_nth_huge_index_on_short_iterator() {
+    let data = [1, 2, 3];
+    let mut iter = data.iter().peekmore();
+
+    assert_eq!(iter.peek_nth(1_000_000), None);
+    assert_eq!(iter.buffered(), &[Some(&1), Some(&2), Some(&3), None]);
+
+    // a second peek past the end doesn't buffer any further
+    assert_eq!(iter.peek_nth(2_000_000), None);
+    assert_eq!(iter.buffered().len(), 4);
+    assert_eq!(iter.peek_nth(2), Some(&&3));
+}
+
+#[test]
+fn test_peek_large_batch_on_short_iterator() {
+    let data: Vec<i32> = (0..10).collect();
+    let mut iter = data.iter().peekmore();
+
+    // batches > 1000 use divide and conquer, which should stop early as well
+    iter.advance_cursor_by(5_000);
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.buffered().len(), 11);
+}
+
+#[test]
+fn test_advance_cursor_by_optimized_huge_jump_on_short_iterator() {
+    let data = [1, 2, 3];
+    let mut iter = data.iter().peekmore();
+
+    iter.advance_cursor_by_optimized(1_000_000);
+    assert_eq!(iter.cursor(), 1_000_000);
+    assert_eq!(iter.peek(), None);
+    assert_eq!(iter.buffered().len(), 4);
+
+    iter.reset_cursor();
+    assert_eq!(iter.peek(), Some(&&1));
+}
+
+#[test]
+fn test_peek_range_large_range_on_short_iterator() {
+    let data = [1, 2, 3];
+    let mut iter = data.iter().peekmore();
+
+    let range = iter.peek_range(1, 3_000);
+    assert_eq!(range.len(), 2_999);
+    assert_eq!(range[0], Some(&2));
+    assert_eq!(range[1], Some(&3));
+    assert!(range[2..].iter().all(Option::is_none));
+
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next(), None);
+}
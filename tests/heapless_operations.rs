@@ -0,0 +1,43 @@
+#![cfg(feature = "heapless")]
+
+use obsessive_peek::{PeekMore, PeekMoreError};
+
+#[test]
+fn heapless_peek_within_capacity() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore_heapless::<3>();
+
+    assert_eq!(iter.peek(), Ok(Some(&&1)));
+    assert_eq!(iter.peek_nth(2), Ok(Some(&&3)));
+    assert_eq!(iter.peek_next(), Ok(Some(&&2)));
+    assert_eq!(iter.cursor(), 1);
+
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.peek(), Ok(Some(&&2)));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next(), Some(&4));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn heapless_peek_at_capacity_boundary() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore_heapless::<2>();
+
+    assert_eq!(iter.peek_nth(1), Ok(Some(&&2)));
+    assert_eq!(iter.peek_nth(2), Err(PeekMoreError::CapacityExceeded));
+
+    // after consuming an element, there is room for the next one
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.peek_nth(1), Ok(Some(&&3)));
+}
+
+#[test]
+fn heapless_peek_zero_capacity() {
+    let iterable = [1];
+    let mut iter = iterable.iter().peekmore_heapless::<0>();
+
+    assert_eq!(iter.peek(), Err(PeekMoreError::CapacityExceeded));
+    assert_eq!(iter.next(), Some(&1));
+}
@@ -0,0 +1,212 @@
+#![cfg(feature = "alloc")]
+
+use obsessive_peek::{PeekMore, PeekMoreError};
+
+#[test]
+fn nth_without_buffer() {
+    let iterable = [0, 1, 2, 3];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.nth(2), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn nth_within_buffer() {
+    let iterable = [0, 1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    let _ = iter.peek_nth(3);
+    iter.advance_cursor_by(3);
+
+    assert_eq!(iter.nth(1), Some(&1));
+    assert_eq!(iter.cursor(), 1);
+    assert_eq!(iter.peek(), Some(&&3));
+    assert_eq!(iter.next(), Some(&2));
+}
+
+#[test]
+fn nth_beyond_buffer() {
+    let iterable = [0, 1, 2, 3, 4, 5];
+    let mut iter = iterable.iter().peekmore();
+
+    let _ = iter.peek_nth(1);
+    iter.advance_cursor_by(5);
+
+    assert_eq!(iter.nth(3), Some(&3));
+    assert_eq!(iter.cursor(), 1);
+    assert_eq!(iter.peek(), Some(&&5));
+    assert_eq!(iter.next(), Some(&4));
+}
+
+#[test]
+fn nth_past_end() {
+    let iterable = [0, 1, 2];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.next(), None);
+
+    // with the end of the iterator buffered
+    let mut iter = iterable.iter().peekmore();
+    let _ = iter.peek_nth(5);
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn nth_past_end_with_cursor_ahead() {
+    let iterable = [0, 1, 2];
+
+    // the cursor moves back by the 3 consumed elements, not by `n + 1`
+    let mut iter = iterable.iter().peekmore();
+    iter.advance_cursor_by(5);
+    assert_eq!(iter.nth(10), None);
+    assert_eq!(iter.cursor(), 2);
+    assert_eq!(iter.consumed(), 3);
+
+    // partially buffered
+    let mut iter = iterable.iter().peekmore();
+    let _ = iter.peek_nth(1);
+    iter.advance_cursor_by(5);
+    assert_eq!(iter.nth(10), None);
+    assert_eq!(iter.cursor(), 2);
+
+    // with the end of the iterator buffered, and padded
+    let mut iter = iterable.iter().peekmore();
+    let _ = iter.peek_range(0, 8);
+    iter.advance_cursor_by(5);
+    assert_eq!(iter.nth(4), None);
+    assert_eq!(iter.cursor(), 2);
+    assert_eq!(iter.consumed(), 3);
+}
+
+#[test]
+fn count_after_mixed_peek_and_consume() {
+    let mut iter = (0..1_000).peekmore();
+
+    let _ = iter.peek_nth(500);
+    let _ = iter.nth(10);
+    let _ = iter.next();
+    iter.advance_cursor_by(700);
+    let _ = iter.peek();
+
+    assert_eq!(iter.count(), 1_000 - 12);
+}
+
+#[test]
+fn count_with_end_buffered() {
+    let iterable = [1, 2, 3];
+    let mut iter = iterable.iter().peekmore();
+
+    let _ = iter.peek_nth(10);
+    let _ = iter.next();
+    assert_eq!(iter.count(), 2);
+
+    let empty: [i32; 0] = [];
+    let mut iter = empty.iter().peekmore();
+    let _ = iter.peek();
+    assert_eq!(iter.count(), 0);
+}
+
+#[test]
+fn last_after_peek_and_consume() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    let _ = iter.peek_nth(1);
+    let _ = iter.next();
+    assert_eq!(iter.last(), Some(&4));
+}
+
+#[test]
+fn last_from_buffer() {
+    let iterable = [1, 2, 3, 4];
+
+    // the end of the iterator is buffered
+    let mut iter = iterable.iter().peekmore();
+    let _ = iter.peek_nth(10);
+    let _ = iter.next();
+    assert_eq!(iter.last(), Some(&4));
+
+    // the last element is buffered, but the end is not
+    let mut iter = iterable.iter().peekmore();
+    let _ = iter.peek_nth(3);
+    assert_eq!(iter.last(), Some(&4));
+
+    let empty: [i32; 0] = [];
+    let mut iter = empty.iter().peekmore();
+    let _ = iter.peek();
+    assert_eq!(iter.last(), None);
+}
+
+#[test]
+fn consumed_and_absolute_cursor() {
+    let mut iter = [1, 2, 3, 4, 5].into_iter().peekmore();
+    assert_eq!(iter.consumed(), 0);
+
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.consumed(), 2);
+
+    iter.advance_cursor();
+    assert_eq!(iter.peek(), Some(&4));
+    assert_eq!(iter.absolute_cursor(), 3);
+}
+
+#[test]
+fn consumed_by_nth_and_truncation() {
+    let mut iter = (0..10).peekmore();
+    iter.peek_nth(2);
+
+    assert_eq!(iter.nth(1), Some(1));
+    assert_eq!(iter.consumed(), 2);
+
+    assert_eq!(iter.nth(3), Some(5));
+    assert_eq!(iter.consumed(), 6);
+
+    iter.advance_cursor_by(2);
+    iter.truncate_iterator_to_cursor();
+    assert_eq!(iter.consumed(), 8);
+
+    // running off the end counts the remaining elements only
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.consumed(), 10);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.consumed(), 10);
+}
+
+#[test]
+fn consumed_by_clear_buffer() {
+    let mut iter = (1..10).peekmore();
+    iter.advance_cursor_to(2);
+
+    assert_eq!(iter.clear_buffer(), vec![1, 2, 3]);
+    assert_eq!(iter.consumed(), 3);
+    assert_eq!(iter.absolute_cursor(), 3);
+    assert_eq!(iter.peek(), Some(&4));
+
+    assert_eq!(iter.next(), Some(4));
+    assert_eq!(iter.consumed(), 4);
+}
+
+#[test]
+fn seek_absolute_position() {
+    let mut iter = [10, 11, 12, 13, 14].into_iter().peekmore();
+    iter.next();
+    iter.next();
+
+    assert_eq!(iter.seek_absolute(3).map(|it| it.cursor()), Ok(1));
+    assert_eq!(iter.peek(), Some(&13));
+    assert_eq!(iter.absolute_cursor(), 3);
+
+    assert_eq!(
+        iter.seek_absolute(1).err(),
+        Some(PeekMoreError::ElementHasBeenConsumed)
+    );
+    assert_eq!(iter.cursor(), 1);
+
+    assert!(iter.seek_absolute(2).is_ok());
+    assert_eq!(iter.peek(), Some(&12));
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "alloc")]
+
 use obsessive_peek::{PeekMore, PeekMoreError};
 
 #[test]
@@ -317,3 +319,828 @@ fn check_peek_nth_empty() {
     assert_eq!(iter.peek_nth(1), None);
     assert_eq!(iter.cursor(), 0);
 }
+
+#[test]
+fn check_peek_min_by_key() {
+    let iterable = [3, 1, 4, 1, 5];
+    let mut iter = iterable.iter().peekmore();
+
+    let min = iter.peek_min_by_key(4, |&&v| v);
+    assert_eq!(min, Some(&&1));
+
+    // ties go to the first occurrence
+    let first_one: *const i32 = &iterable[1];
+    assert!(core::ptr::eq(
+        *iter.peek_min_by_key(4, |&&v| v).unwrap(),
+        first_one
+    ));
+
+    // nothing was consumed
+    assert_eq!(iter.next(), Some(&3));
+}
+
+#[test]
+fn check_peek_max_by_key() {
+    let iterable = [3, 1, 4, 1, 5];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_max_by_key(4, |&&v| v), Some(&&4));
+    assert_eq!(iter.peek_max_by_key(10, |&&v| v), Some(&&5));
+
+    // ties go to the first occurrence
+    let pairs = [(1, 'a'), (2, 'b'), (2, 'c')];
+    let mut iter = pairs.iter().peekmore();
+    assert_eq!(iter.peek_max_by_key(3, |&&(k, _)| k), Some(&&(2, 'b')));
+}
+
+#[test]
+fn check_peek_min_max_by_key_empty() {
+    let iterable: [i32; 0] = [];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_min_by_key(3, |&&v| v), None);
+    assert_eq!(iter.peek_max_by_key(0, |&&v| v), None);
+}
+
+#[test]
+fn check_peek_argmin_argmax() {
+    let iterable = [3, 1, 4, 1];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_argmin(4), Some(1));
+    assert_eq!(iter.peek_argmax(4), Some(2));
+    assert_eq!(iter.peek_argmax(2), Some(0));
+
+    // ties go to the first occurrence
+    let mut iter = [2, 5, 5, 2].into_iter().peekmore();
+    assert_eq!(iter.peek_argmin(4), Some(0));
+    assert_eq!(iter.peek_argmax(4), Some(1));
+
+    // indices are relative to the first unconsumed element, not the cursor
+    iter.advance_cursor();
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.peek_argmin(3), Some(2));
+
+    let mut empty = core::iter::empty::<i32>().peekmore();
+    assert_eq!(empty.peek_argmin(3), None);
+    assert_eq!(empty.peek_argmax(0), None);
+}
+
+#[test]
+fn check_peek_scan_prefix_sum() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    let sums = iter.peek_scan(4, 0, |sum, &&v| {
+        *sum += v;
+        if *sum > 5 { None } else { Some(*sum) }
+    });
+    assert_eq!(sums, vec![1, 3]);
+
+    // without a stop condition, the window bounds the scan
+    let sums = iter.peek_scan(3, 0, |sum, &&v| {
+        *sum += v;
+        Some(*sum)
+    });
+    assert_eq!(sums, vec![1, 3, 6]);
+
+    // nothing was consumed, and the cursor did not move
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.next(), Some(&1));
+}
+
+#[test]
+fn check_peek_scan_past_end() {
+    let iterable = [1, 2];
+    let mut iter = iterable.iter().peekmore();
+
+    let sums = iter.peek_scan(10, 0, |sum, &&v| {
+        *sum += v;
+        Some(*sum)
+    });
+    assert_eq!(sums, vec![1, 3]);
+}
+
+#[test]
+fn check_peek_step_by() {
+    let iterable = [0, 1, 2, 3, 4, 5];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_step_by(3, 2), vec![&&0, &&2, &&4]);
+    assert_eq!(iter.peek_step_by(5, 2), vec![&&0, &&2, &&4]);
+    assert_eq!(iter.peek_step_by(2, 1), vec![&&0, &&1]);
+    assert!(iter.peek_step_by(0, 3).is_empty());
+
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.peek_step_by(2, 3), vec![&&1, &&4]);
+}
+
+#[test]
+#[should_panic]
+fn check_peek_step_by_zero_step() {
+    let iterable = [0, 1, 2];
+    let mut iter = iterable.iter().peekmore();
+
+    let _ = iter.peek_step_by(2, 0);
+}
+
+#[test]
+fn check_peek_partition_point() {
+    let iterable = [1, 2, 3, 4, 5];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_partition_point(|&&x| x < 3), 2);
+    assert_eq!(iter.peek_partition_point(|&&x| x < 10), 5);
+    assert_eq!(iter.peek_partition_point(|&&x| x < 0), 0);
+
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.peek_partition_point(|&&x| x < 3), 1);
+}
+
+#[test]
+fn check_peek_partition_point_empty() {
+    let iterable: [i32; 0] = [];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_partition_point(|&&x| x < 3), 0);
+}
+
+#[test]
+fn check_buffered() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    assert!(iter.buffered().is_empty());
+
+    assert_eq!(iter.peek_nth(2), Some(&&3));
+    assert_eq!(iter.buffered(), &[Some(&1), Some(&2), Some(&3)]);
+
+    let _ = iter.next();
+    assert_eq!(iter.buffered(), &[Some(&2), Some(&3)]);
+}
+
+#[test]
+fn check_last_buffered() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.last_buffered(), None);
+
+    let _ = iter.peek_nth(2);
+    assert_eq!(iter.last_buffered(), Some(&&3));
+
+    // trailing `None`s are skipped
+    let _ = iter.peek_nth(6);
+    assert_eq!(iter.last_buffered(), Some(&&4));
+}
+
+#[test]
+fn check_peek_nth_stops_at_exhaustion() {
+    let iterable = [1, 2, 3];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_nth(1), Some(&&2));
+    assert_eq!(iter.queue.len(), 2);
+
+    assert_eq!(iter.peek_nth(1_000_000), None);
+    assert_eq!(iter.queue.len(), 4);
+    assert_eq!(iter.peek_nth(2), Some(&&3));
+
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next(), Some(&2));
+    assert_eq!(iter.next(), Some(&3));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn check_peek_nth_lazy() {
+    let iterable = [1, 2, 3];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_nth_lazy(1), Some(&&2));
+    assert_eq!(iter.queue.len(), 2);
+
+    assert_eq!(iter.peek_nth_lazy(1_000_000), None);
+    assert_eq!(iter.queue.len(), 4);
+    assert_eq!(iter.peek_nth_lazy(2), Some(&&3));
+    assert_eq!(iter.queue.len(), 4);
+}
+
+#[test]
+fn check_peek_rev() {
+    let iterable = [1, 2, 3];
+    let mut iter = iterable.iter().peekmore();
+
+    let reversed: Vec<&&i32> = iter.peek_rev(3).collect();
+    assert_eq!(reversed, vec![&&3, &&2, &&1]);
+
+    let reversed: Vec<&&i32> = iter.peek_rev(2).collect();
+    assert_eq!(reversed, vec![&&2, &&1]);
+
+    let reversed: Vec<&&i32> = iter.peek_rev(10).collect();
+    assert_eq!(reversed, vec![&&3, &&2, &&1]);
+
+    assert_eq!(iter.peek_rev(0).next(), None);
+    assert_eq!(iter.next(), Some(&1));
+}
+
+#[test]
+fn check_peek_two() {
+    let iterable = [1, 2, 3];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_two(), (Some(&&1), Some(&&2)));
+
+    iter.advance_cursor();
+    assert_eq!(iter.peek_two(), (Some(&&2), Some(&&3)));
+
+    iter.advance_cursor();
+    assert_eq!(iter.peek_two(), (Some(&&3), None));
+    assert_eq!(iter.cursor(), 2);
+
+    iter.advance_cursor();
+    assert_eq!(iter.peek_two(), (None, None));
+}
+
+#[test]
+fn check_peek_pair_at() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    assert_eq!(iter.peek_pair_at(2), (Some(&&3), Some(&&4)));
+    assert_eq!(iter.peek_pair_at(0), (Some(&&1), Some(&&2)));
+    assert_eq!(iter.peek_pair_at(3), (Some(&&4), None));
+    assert_eq!(iter.peek_pair_at(10), (None, None));
+
+    // independent of the cursor
+    iter.advance_cursor_by(3);
+    assert_eq!(iter.peek_pair_at(1), (Some(&&2), Some(&&3)));
+    assert_eq!(iter.cursor(), 3);
+}
+
+#[test]
+fn check_peek_cursor_and_offset() {
+    let iterable = [1, 2, 3, 4];
+    let mut iter = iterable.iter().peekmore();
+
+    iter.advance_cursor();
+    assert_eq!(iter.peek_cursor_and_offset(2), (Some(&&2), Some(&&4)));
+    assert_eq!(iter.peek_cursor_and_offset(1), (Some(&&2), Some(&&3)));
+    assert_eq!(iter.peek_cursor_and_offset(0), (Some(&&2), Some(&&2)));
+    assert_eq!(iter.peek_cursor_and_offset(3), (Some(&&2), None));
+    assert_eq!(iter.cursor(), 1);
+
+    iter.advance_cursor_by(5);
+    assert_eq!(iter.peek_cursor_and_offset(1), (None, None));
+}
+
+#[test]
+fn check_lookahead_limit_boundary() {
+    let mut iter = (0..).peekmore_limited(3);
+
+    assert_eq!(iter.lookahead_limit(), Some(3));
+    assert_eq!(iter.try_peek_nth(2), Ok(Some(&2)));
+    assert_eq!(iter.try_peek_nth(3), Err(PeekMoreError::LookaheadExceeded));
+
+    // infallible methods don't buffer beyond the limit
+    assert_eq!(iter.peek_nth(3), None);
+    assert_eq!(iter.peek_nth(1_000), None);
+    assert_eq!(iter.buffered().len(), 3);
+    assert_eq!(iter.peek_amount(5), &[Some(0), Some(1), Some(2)]);
+
+    // consuming an element moves the window
+    assert_eq!(iter.next(), Some(0));
+    assert_eq!(iter.try_peek_nth(2), Ok(Some(&3)));
+    assert_eq!(iter.buffered().len(), 3);
+}
+
+#[test]
+fn check_lookahead_limit_advance_cursor() {
+    let mut iter = (0..).peekmore_limited(3);
+
+    assert!(iter.try_advance_cursor_by(2).is_ok());
+    assert_eq!(iter.peek(), Some(&2));
+
+    assert_eq!(
+        iter.try_advance_cursor_by(1).map(|it| it.cursor()),
+        Err(PeekMoreError::LookaheadExceeded)
+    );
+    assert_eq!(iter.cursor(), 2);
+}
+
+#[test]
+fn check_lookahead_limit_whole_buffer_methods() {
+    // methods which buffer to the end stop at the limit
+    let mut iter = (0..10).peekmore_limited(3);
+    iter.advance_cursor();
+    assert_eq!(iter.cursor_distance_to_end(), 2);
+    assert_eq!(iter.try_peek_last(5), Err(PeekMoreError::LookaheadExceeded));
+
+    assert!(iter.contains_ahead(&2));
+    assert!(!iter.contains_ahead(&5));
+    assert_eq!(iter.position_ahead(&5), None);
+    assert_eq!(iter.peek_partition_point(|&v| v < 5), 3);
+    assert_eq!(iter.buffered_len(), 3);
+}
+
+#[test]
+fn check_lookahead_limit_finite_iterator() {
+    let iterable = [1, 2];
+    let mut iter = iterable.iter().peekmore_limited(5);
+
+    assert_eq!(iter.try_peek_nth(4), Ok(None));
+    assert_eq!(iter.peek_amount(4), &[Some(&1), Some(&2), None, None]);
+
+    let mut unlimited = iterable.iter().peekmore();
+    assert_eq!(unlimited.lookahead_limit(), None);
+    assert_eq!(unlimited.try_peek_nth(1_000), Ok(None));
+}
+
+#[test]
+fn match_prefix_longest_candidate() {
+    let mut iter = [1, 2, 9].into_iter().peekmore();
+    let candidates: &[&[i32]] = &[&[1], &[1, 2], &[1, 2, 3]];
+
+    assert_eq!(iter.match_prefix(candidates), Some((1, &[1, 2][..])));
+
+    // nothing was consumed
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn match_prefix_ties_and_misses() {
+    let mut iter = [1, 2].into_iter().peekmore();
+
+    let tied: &[&[i32]] = &[&[1, 2], &[3], &[1, 2]];
+    assert_eq!(iter.match_prefix(tied), Some((0, &[1, 2][..])));
+
+    let missing: &[&[i32]] = &[&[2], &[1, 2, 3]];
+    assert_eq!(iter.match_prefix(missing), None);
+
+    let empty: &[&[i32]] = &[];
+    assert_eq!(iter.match_prefix(empty), None);
+}
+
+#[test]
+fn peek_rle_runs() {
+    let mut iter = [1, 1, 2, 3, 3, 3].into_iter().peekmore();
+
+    assert_eq!(iter.peek_rle(6), vec![(1, 2), (2, 1), (3, 3)]);
+    assert_eq!(iter.peek_rle(4), vec![(1, 2), (2, 1), (3, 1)]);
+    assert_eq!(iter.peek_rle(10), vec![(1, 2), (2, 1), (3, 3)]);
+    assert!(iter.peek_rle(0).is_empty());
+
+    // nothing was consumed
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn contains_ahead_membership() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+
+    assert!(iter.contains_ahead(&2));
+    assert!(!iter.contains_ahead(&9));
+
+    // nothing was consumed
+    assert_eq!(iter.next(), Some(1));
+    assert!(!iter.contains_ahead(&1));
+}
+
+#[test]
+fn contains_ahead_stops_on_match() {
+    let mut iter = (1..).peekmore();
+
+    assert!(iter.contains_ahead(&2));
+    assert_eq!(iter.buffered(), &[Some(1), Some(2)]);
+}
+
+#[test]
+fn position_ahead_offsets() {
+    let mut iter = [10, 20, 30].iter().peekmore();
+
+    assert_eq!(iter.position_ahead(&&20), Some(1));
+    assert_eq!(iter.buffered(), &[Some(&10), Some(&20)]);
+    assert_eq!(iter.position_ahead(&&10), Some(0));
+    assert_eq!(iter.position_ahead(&&99), None);
+
+    // indices are relative to the front, not the cursor
+    iter.advance_cursor_by(2);
+    assert_eq!(iter.position_ahead(&&30), Some(2));
+    assert_eq!(iter.next(), Some(&10));
+    assert_eq!(iter.position_ahead(&&30), Some(1));
+}
+
+#[test]
+fn with_peeked_mut_sorts_window() {
+    let mut iter = [4, 2, 3, 1, 0].into_iter().peekmore();
+
+    let len = iter.with_peeked_mut(4, |window| {
+        window.sort();
+        window.len()
+    });
+    assert_eq!(len, 4);
+
+    let consumed: Vec<i32> = iter.collect();
+    assert_eq!(consumed, vec![1, 2, 3, 4, 0]);
+}
+
+#[test]
+fn try_peek_last_finite() {
+    let mut iter = [1, 2, 3].into_iter().peekmore();
+
+    assert_eq!(iter.try_peek_last(2), Err(PeekMoreError::LookaheadExceeded));
+    assert_eq!(iter.try_peek_last(3), Ok(Some(&3)));
+    assert_eq!(iter.next(), Some(1));
+
+    let mut empty = core::iter::empty::<i32>().peekmore();
+    assert_eq!(empty.try_peek_last(0), Ok(None));
+}
+
+#[test]
+fn try_peek_last_infinite_hits_cap() {
+    let mut iter = core::iter::repeat(1).peekmore();
+
+    assert_eq!(
+        iter.try_peek_last(1000),
+        Err(PeekMoreError::LookaheadExceeded)
+    );
+    assert!(iter.buffered().len() <= 1001);
+}
+
+#[test]
+fn try_peek_last_respects_lookahead_limit() {
+    let mut iter = [1, 2, 3].into_iter().peekmore_limited(2);
+
+    assert_eq!(
+        iter.try_peek_last(10),
+        Err(PeekMoreError::LookaheadExceeded)
+    );
+}
+
+#[test]
+fn diff_with_first_mismatch() {
+    let mut iter = [1, 2, 9].into_iter().peekmore();
+
+    assert_eq!(iter.diff_with(&[1, 2, 3]), Some(2));
+    assert_eq!(iter.diff_with(&[0]), Some(0));
+    assert_eq!(iter.diff_with(&[1, 2, 9]), None);
+    assert_eq!(iter.diff_with::<i32>(&[]), None);
+
+    // running out of elements counts as a difference
+    assert_eq!(iter.diff_with(&[1, 2, 9, 4]), Some(3));
+
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn is_exhausted_with_option_elements() {
+    let mut iter = [Some(1), None].into_iter().peekmore();
+
+    assert!(!iter.is_exhausted());
+    assert_eq!(iter.next(), Some(Some(1)));
+
+    assert_eq!(iter.peek(), Some(&None));
+    assert!(!iter.is_exhausted());
+
+    iter.advance_cursor_by(3);
+    assert!(iter.is_exhausted());
+
+    iter.reset_cursor();
+    assert_eq!(iter.next(), Some(None));
+    assert!(iter.is_exhausted());
+}
+
+#[test]
+fn is_exhausted_at_lookahead_limit() {
+    let mut iter = (0..).peekmore_limited(1);
+
+    iter.advance_cursor();
+    assert_eq!(iter.peek(), None);
+    assert!(!iter.is_exhausted());
+}
+
+#[test]
+fn peek_window_sum_over_window() {
+    let mut iter = [1, 2, 3, 4].into_iter().peekmore();
+
+    assert_eq!(iter.peek_window_sum(3), 6);
+    assert_eq!(iter.peek_window_sum(10), 10);
+    assert_eq!(iter.peek_window_sum(0), 0);
+
+    // the iterator is unchanged
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn peek_matching_nested() {
+    let mut iter = ['(', '(', ')', ')'].into_iter().peekmore();
+
+    assert_eq!(iter.peek_matching(&'(', &')'), Some(3));
+    assert_eq!(iter.next(), Some('('));
+    assert_eq!(iter.peek_matching(&'(', &')'), Some(1));
+}
+
+#[test]
+fn peek_matching_unbalanced() {
+    let mut unclosed = ['(', '(', ')'].into_iter().peekmore();
+    assert_eq!(unclosed.peek_matching(&'(', &')'), None);
+    assert_eq!(unclosed.next(), Some('('));
+
+    let mut not_open = [')', '(', ')'].into_iter().peekmore();
+    assert_eq!(not_open.peek_matching(&'(', &')'), None);
+}
+
+#[test]
+fn peek_split_first_head_and_tail() {
+    let mut iter = [1, 2, 3].iter().peekmore();
+
+    assert_eq!(
+        iter.peek_split_first(3),
+        Some((&&1, &[Some(&2), Some(&3)][..]))
+    );
+    assert_eq!(iter.peek_split_first(1), Some((&&1, &[][..])));
+    assert_eq!(iter.peek_split_first(0), None);
+
+    let mut empty = core::iter::empty::<i32>().peekmore();
+    assert_eq!(empty.peek_split_first(2), None);
+}
+
+#[test]
+fn peek_period_repeating() {
+    let mut iter = [1, 2, 1, 2, 1, 2].into_iter().peekmore();
+
+    assert_eq!(iter.peek_period(2), Some(2));
+    assert_eq!(iter.peek_period(3), Some(2));
+    assert_eq!(iter.peek_period(1), None);
+    assert_eq!(iter.peek_period(0), None);
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn peek_period_needs_visible_repetition() {
+    let mut constant = core::iter::repeat(7).peekmore();
+    assert_eq!(constant.peek_period(4), Some(1));
+
+    let mut short = [1, 2, 3].into_iter().peekmore();
+    assert_eq!(short.peek_period(3), None);
+
+    let mut irregular = [1, 2, 3, 1, 2, 4].into_iter().peekmore();
+    assert_eq!(irregular.peek_period(3), None);
+}
+
+#[test]
+fn peek_period_huge_max_period() {
+    let mut short = [1, 2, 3].into_iter().peekmore();
+    assert_eq!(short.peek_period(usize::MAX), None);
+
+    let mut constant = [5, 5, 5].into_iter().peekmore();
+    assert_eq!(constant.peek_period(usize::MAX), Some(1));
+}
+
+#[test]
+fn peek_nth_back_from_end() {
+    let mut iter = [1, 2, 3, 4, 5].into_iter().peekmore();
+
+    assert_eq!(iter.peek_nth_back(0), Some(&5));
+    assert_eq!(iter.peek_nth_back(2), Some(&3));
+    assert_eq!(iter.peek_nth_back(4), Some(&1));
+    assert_eq!(iter.peek_nth_back(5), None);
+
+    // nothing was consumed
+    assert_eq!(iter.peek(), Some(&1));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn peek_nth_back_with_front_peeks() {
+    let mut iter = [1, 2, 3, 4, 5].into_iter().peekmore();
+
+    assert_eq!(iter.peek_nth(1), Some(&2));
+    assert_eq!(iter.peek_nth_back(1), Some(&4));
+
+    // the front and the back meet
+    assert_eq!(iter.peek_nth(4), Some(&5));
+    assert_eq!(iter.peek_nth(5), None);
+    assert_eq!(iter.peek_nth_back(3), Some(&2));
+    assert_eq!(iter.peek_nth_back(4), Some(&1));
+    assert_eq!(iter.peek_nth_back(5), None);
+
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.peek_nth_back(3), Some(&2));
+    assert_eq!(iter.collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+}
+
+#[test]
+fn peek_nth_back_iterator_overrides() {
+    let mut iter = (1..=6).peekmore();
+    iter.peek_nth_back(2);
+    assert_eq!(iter.clone().count(), 6);
+    assert_eq!(iter.clone().last(), Some(6));
+    assert_eq!(iter.nth(4), Some(5));
+    assert_eq!(iter.next(), Some(6));
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn peek_counts_over_window() {
+    let mut iter = [1, 2, 2, 3, 3, 3].into_iter().peekmore();
+
+    let counts = iter.peek_counts(6);
+    assert_eq!(
+        counts.into_iter().collect::<Vec<_>>(),
+        vec![(1, 1), (2, 2), (3, 3)]
+    );
+
+    let counts = iter.peek_counts(4);
+    assert_eq!(
+        counts.into_iter().collect::<Vec<_>>(),
+        vec![(1, 1), (2, 2), (3, 1)]
+    );
+
+    assert!(iter.peek_counts(0).is_empty());
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn peek_chunks_by_difference() {
+    let mut iter = [1, 2, 4, 5, 7].into_iter().peekmore();
+
+    let chunks = iter.peek_chunks_by(5, |a, b| b - a <= 1);
+    assert_eq!(
+        chunks,
+        vec![
+            &[Some(1), Some(2)][..],
+            &[Some(4), Some(5)][..],
+            &[Some(7)][..]
+        ]
+    );
+
+    let chunks = iter.peek_chunks_by(10, |_, _| true);
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks[0].len(), 5);
+
+    assert!(iter.peek_chunks_by(0, |_, _| true).is_empty());
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn peek_any_of_cursor_element() {
+    let mut iter = ['a', 'b'].into_iter().peekmore();
+
+    assert!(iter.peek_any_of(&['x', 'a']));
+    assert!(!iter.peek_any_of(&['x', 'y']));
+    assert!(!iter.peek_any_of::<char>(&[]));
+
+    iter.advance_cursor();
+    assert!(iter.peek_any_of(&['b']));
+    assert!(!iter.peek_any_of(&['a']));
+
+    iter.advance_cursor();
+    assert!(!iter.peek_any_of(&['a', 'b']));
+
+    // nothing was consumed
+    assert_eq!(iter.next(), Some('a'));
+}
+
+#[test]
+fn peeks_walks_lazily() {
+    let mut iter = [1, 2, 3, 4].into_iter().peekmore();
+    iter.advance_cursor();
+
+    let mut seen = Vec::new();
+    let mut peeks = iter.peeks();
+    while let Some(&value) = peeks.next_peek() {
+        seen.push(value);
+
+        if seen.len() == 3 {
+            break;
+        }
+    }
+    assert_eq!(peeks.position(), 3);
+
+    assert_eq!(seen, vec![1, 2, 3]);
+    assert_eq!(iter.buffered_len(), 3);
+    assert_eq!(iter.cursor(), 1);
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn peeks_stops_at_end() {
+    let mut iter = [1, 2].into_iter().peekmore();
+    let mut peeks = iter.peeks();
+
+    assert_eq!(peeks.next_peek(), Some(&1));
+    assert_eq!(peeks.next_peek(), Some(&2));
+    assert_eq!(peeks.next_peek(), None);
+    assert_eq!(peeks.next_peek(), None);
+    assert_eq!(peeks.position(), 2);
+}
+
+#[test]
+fn peek_all_equal_window() {
+    let mut same = [5, 5, 5].into_iter().peekmore();
+    assert!(same.peek_all_equal(3));
+    assert!(same.peek_all_equal(10));
+
+    let mut different = [5, 5, 6].into_iter().peekmore();
+    assert!(!different.peek_all_equal(3));
+    assert!(different.peek_all_equal(2));
+    assert!(different.peek_all_equal(1));
+    assert!(different.peek_all_equal(0));
+    assert_eq!(different.next(), Some(5));
+
+    let mut empty = core::iter::empty::<i32>().peekmore();
+    assert!(empty.peek_all_equal(3));
+}
+
+#[test]
+fn peek_filtered_skips_non_matching() {
+    let mut iter = [1, 0, 2, 0, 3].into_iter().peekmore();
+
+    assert_eq!(iter.peek_filtered(2, |&v| v != 0), vec![&1, &2]);
+    assert_eq!(iter.buffered_len(), 3);
+
+    assert_eq!(iter.peek_filtered(5, |&v| v != 0), vec![&1, &2, &3]);
+    assert!(iter.peek_filtered(0, |&v| v != 0).is_empty());
+    assert_eq!(iter.next(), Some(1));
+}
+
+#[test]
+fn peek_cow_borrows_cursor_element() {
+    let mut iter = ["a".to_string(), "b".to_string()].into_iter().peekmore();
+    iter.advance_cursor();
+
+    let cow = iter.peek_cow().unwrap();
+    assert!(matches!(cow, std::borrow::Cow::Borrowed(_)));
+    assert_eq!(cow.as_str(), "b");
+
+    let owned: String = cow.into_owned();
+    assert_eq!(iter.peek(), Some(&owned));
+
+    iter.advance_cursor();
+    assert!(iter.peek_cow().is_none());
+}
+
+#[test]
+fn is_peeked_sorted_by_comparator() {
+    let mut iter = [5, 4, 3].into_iter().peekmore();
+
+    assert!(iter.is_peeked_sorted_by(3, |a, b| a >= b));
+    assert!(!iter.is_peeked_sorted_by(3, |a, b| a <= b));
+    assert!(iter.is_peeked_sorted_by(1, |a, b| a <= b));
+    assert!(iter.is_peeked_sorted_by(10, |a, b| a > b));
+
+    // sorted by key, ignoring the sign
+    let mut iter = [1, -2, 3, -4].into_iter().peekmore();
+    assert!(iter.is_peeked_sorted_by(4, |a: &i32, b: &i32| a.abs() <= b.abs()));
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.next(), Some(1));
+
+    let mut empty = core::iter::empty::<i32>().peekmore();
+    assert!(empty.is_peeked_sorted_by(3, |a, b| a <= b));
+}
+
+#[test]
+fn peek_reduce_max_with_sentinel() {
+    use core::ops::ControlFlow;
+
+    let max_until_sentinel = |max: i32, &v: &i32| {
+        if v < 0 {
+            ControlFlow::Break(max)
+        } else {
+            ControlFlow::Continue(max.max(v))
+        }
+    };
+
+    let mut iter = [2, 8, 5, -1, 100].into_iter().peekmore();
+    assert_eq!(iter.peek_reduce(5, max_until_sentinel), Some(8));
+    assert_eq!(iter.peek_reduce(2, max_until_sentinel), Some(8));
+    assert_eq!(iter.peek_reduce(1, max_until_sentinel), Some(2));
+    assert_eq!(iter.peek_reduce(0, max_until_sentinel), None);
+    assert_eq!(iter.next(), Some(2));
+
+    let mut empty = core::iter::empty::<i32>().peekmore();
+    assert_eq!(empty.peek_reduce(3, max_until_sentinel), None);
+}
+
+#[test]
+fn peek_take_while_map_halves_evens() {
+    let mut iter = [2, 4, 6, 7].into_iter().peekmore();
+    let halve_even = |&v: &i32| (v % 2 == 0).then_some(v / 2);
+
+    assert_eq!(iter.peek_take_while_map(halve_even), vec![1, 2, 3]);
+    assert_eq!(iter.cursor(), 0);
+    assert_eq!(iter.buffered_some_len(), 4);
+    assert_eq!(iter.consumed(), 0);
+    assert_eq!(iter.collect::<Vec<_>>(), vec![2, 4, 6, 7]);
+
+    let mut all_even = [2, 4].into_iter().peekmore();
+    assert_eq!(all_even.peek_take_while_map(halve_even), vec![1, 2]);
+
+    let mut empty = core::iter::empty::<i32>().peekmore();
+    assert!(empty.peek_take_while_map(halve_even).is_empty());
+}
@@ -1,4 +1,6 @@
-use obsessive_peek::PeekMore;
+#![cfg(feature = "alloc")]
+
+use obsessive_peek::{PeekMore, PeekMoreError};
 
 #[test]
 fn peek_range_from_start_smaller_than_input_len() {
@@ -36,6 +38,119 @@ fn peek_range_from_start_bigger_than_input_len() {
     assert_eq!(view.len(), 6);
 }
 
+#[test]
+fn peek_range_len_after_short_fill() {
+    let mut peeking_queue = [0, 1, 2, 3].iter().peekmore();
+
+    // buffer up to the end of the iterator, which stops at the first `None`
+    assert_eq!(peeking_queue.peek_nth(10), None);
+    assert_eq!(peeking_queue.buffered_some_len(), 4);
+    assert_eq!(peeking_queue.buffered_len(), 5);
+
+    let view = peeking_queue.peek_range(0, 6);
+    assert_eq!(view.len(), 6);
+    assert_eq!(&view[4..], &[None, None]);
+
+    // the padding is stored in the queue
+    assert_eq!(peeking_queue.buffered_len(), 6);
+
+    let view = peeking_queue.peek_range(5, 9);
+    assert_eq!(view, &[None, None, None, None]);
+    assert_eq!(peeking_queue.buffered_len(), 9);
+    assert_eq!(peeking_queue.buffered_some_len(), 4);
+
+    let view = peeking_queue.peek_range(2, 2);
+    assert!(view.is_empty());
+}
+
+#[test]
+fn peek_range_padding_then_fill_to() {
+    let mut peeking_queue = [0, 1].into_iter().peekmore();
+    let _ = peeking_queue.peek_range(0, 5);
+
+    // nothing more is buffered, and the padding is kept
+    peeking_queue.fill_to(20);
+    assert_eq!(peeking_queue.buffered_len(), 5);
+    assert_eq!(peeking_queue.buffered_some_len(), 2);
+    assert_eq!(peeking_queue.peek_nth(3), None);
+    assert_eq!(peeking_queue.collect::<Vec<_>>(), vec![0, 1]);
+}
+
+#[test]
+fn peek_range_padding_then_chain_peekmore() {
+    let mut peeking_queue = [0, 1].into_iter().peekmore();
+    let _ = peeking_queue.peek_range(0, 5);
+
+    let mut chained = peeking_queue.chain_peekmore([2, 3]);
+    assert_eq!(chained.buffered_len(), 2);
+    assert_eq!(chained.peek_range(1, 5), &[Some(1), Some(2), Some(3), None]);
+    assert_eq!(chained.collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+}
+
+#[test]
+fn peek_range_len_for_large_ranges() {
+    let mut peeking_queue = (0..10).peekmore();
+    let view = peeking_queue.peek_range(0, 2500);
+
+    assert_eq!(view.len(), 2500);
+    assert_eq!(view[9], Some(9));
+    assert!(view[10..].iter().all(Option::is_none));
+
+    let mut peeking_queue = (0..3000).peekmore();
+    let view = peeking_queue.peek_range(100, 3100);
+
+    assert_eq!(view.len(), 3000);
+    assert_eq!(view[0], Some(100));
+    assert_eq!(view[2899], Some(2999));
+    assert!(view[2900..].iter().all(Option::is_none));
+}
+
+#[test]
+fn peek_range_len_cut_off_by_limit() {
+    let mut peeking_queue = (0..).peekmore_limited(3);
+    let view = peeking_queue.peek_range(1, 5);
+
+    assert_eq!(view, &[Some(1), Some(2)]);
+}
+
+#[test]
+fn peek_range_padding_cut_off_by_limit() {
+    let mut peeking_queue = [1].into_iter().peekmore_limited(2);
+
+    assert_eq!(peeking_queue.peek_range(0, 10), &[Some(1), None]);
+    assert_eq!(peeking_queue.buffered_len(), 2);
+
+    assert_eq!(peeking_queue.peek_range(1, usize::MAX), &[None]);
+    assert_eq!(peeking_queue.buffered_len(), 2);
+}
+
+#[test]
+fn peek_range_padding_is_handled_by_consumers() {
+    let padded = || {
+        let mut iter = [1, 2, 3].into_iter().peekmore();
+        let _ = iter.peek_range(0, 8);
+        iter
+    };
+
+    assert_eq!(padded().count(), 3);
+    assert_eq!(padded().last(), Some(3));
+    assert_eq!(padded().collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(padded().take_peekmore(5).collect::<Vec<_>>(), vec![1, 2, 3]);
+    assert_eq!(padded().peek_nth_back(0), Some(&3));
+
+    let mut iter = padded();
+    iter.insert_peeked(6, 4);
+    assert_eq!(iter.buffered_some_len(), 4);
+    iter.debug_assert_invariants();
+    assert_eq!(iter.collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+    let mut iter = padded();
+    iter.advance_cursor_by(5);
+    iter.truncate_iterator_to_cursor();
+    assert_eq!(iter.consumed(), 3);
+    assert_eq!(iter.next(), None);
+}
+
 #[test]
 fn peek_range_from_middle() {
     let mut peeking_queue = [0, 1, 2, 3].iter().peekmore();
@@ -170,3 +285,91 @@ fn peek_amount_renewed_view() {
     assert_eq!(view[0], Some(&1));
     assert_eq!(view[1], Some(&2));
 }
+
+#[test]
+fn peek_rwindows_from_end() {
+    let mut peeking_queue = [1, 2, 3, 4].iter().peekmore();
+    let windows = peeking_queue.peek_rwindows(2);
+
+    assert_eq!(windows, vec![vec![&3, &4], vec![&2, &3], vec![&1, &2]]);
+
+    // nothing has been consumed
+    assert_eq!(peeking_queue.next(), Some(&1));
+}
+
+#[test]
+fn peek_rwindows_size_bigger_than_input_len() {
+    let mut peeking_queue = [1, 2].iter().peekmore();
+    let windows = peeking_queue.peek_rwindows(3);
+
+    assert!(windows.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn peek_rwindows_panic_on_zero_size() {
+    let mut peeking_queue = [1, 2].iter().peekmore();
+    let _ = peeking_queue.peek_rwindows(0);
+}
+
+#[test]
+fn try_peek_range_invalid() {
+    let mut iter = [0, 1, 2, 3].iter().peekmore();
+
+    assert_eq!(
+        iter.try_peek_range(2, 1),
+        Err(PeekMoreError::InvalidRange { start: 2, end: 1 })
+    );
+    assert!(iter.buffered().is_empty());
+}
+
+#[test]
+fn try_peek_range_invalid_bounds() {
+    let mut iter = [0, 1, 2, 3].iter().peekmore();
+
+    match iter.try_peek_range(7, 3) {
+        Err(PeekMoreError::InvalidRange { start, end }) => {
+            assert_eq!(start, 7);
+            assert_eq!(end, 3);
+        }
+        other => panic!("expected an invalid range error, got {:?}", other),
+    }
+}
+
+#[test]
+fn invalid_range_display() {
+    let error = PeekMoreError::InvalidRange { start: 7, end: 3 };
+
+    assert_eq!(
+        error.to_string(),
+        "invalid range: start 7 is greater than end 3"
+    );
+}
+
+#[test]
+fn try_peek_range_matches_peek_range() {
+    let mut iter = [0, 1, 2, 3].iter().peekmore();
+
+    for (start, end) in [(0, 0), (0, 2), (1, 4), (2, 6), (5, 7)] {
+        let expected = iter.peek_range(start, end).to_vec();
+        assert_eq!(iter.try_peek_range(start, end), Ok(expected.as_slice()));
+    }
+}
+
+#[test]
+fn peek_range_copied_matches_peek_range() {
+    let mut iter = (0..4).peekmore();
+
+    for (start, end) in [(0, 0), (0, 2), (1, 4), (2, 6), (5, 7)] {
+        let copied = iter.peek_range_copied(start, end);
+        assert_eq!(copied.as_slice(), iter.peek_range(start, end));
+    }
+}
+
+#[test]
+#[should_panic]
+fn peek_range_copied_invalid_range() {
+    let mut iter = (0..4).peekmore();
+
+    let _ = iter.peek_range_copied(2, 1);
+}
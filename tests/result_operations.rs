@@ -0,0 +1,73 @@
+#![cfg(feature = "alloc")]
+
+use obsessive_peek::PeekMore;
+
+#[test]
+fn peek_oks_stops_at_first_err() {
+    let results: [Result<i32, &str>; 4] = [Ok(1), Ok(2), Err("x"), Ok(3)];
+    let mut iter = results.into_iter().peekmore();
+
+    let (oks, err) = iter.peek_oks(4);
+    assert_eq!(oks, vec![&1, &2]);
+    assert_eq!(err, Some(&"x"));
+
+    // nothing was consumed
+    assert_eq!(iter.next(), Some(Ok(1)));
+}
+
+#[test]
+fn peek_oks_window_before_err() {
+    let results: [Result<i32, &str>; 4] = [Ok(1), Ok(2), Err("x"), Ok(3)];
+    let mut iter = results.into_iter().peekmore();
+
+    let (oks, err) = iter.peek_oks(2);
+    assert_eq!(oks, vec![&1, &2]);
+    assert_eq!(err, None);
+
+    let (oks, err) = iter.peek_oks(10);
+    assert_eq!(oks.len(), 2);
+    assert_eq!(err, Some(&"x"));
+}
+
+#[test]
+fn peek_oks_all_ok() {
+    let results: [Result<i32, &str>; 2] = [Ok(1), Ok(2)];
+    let mut iter = results.into_iter().peekmore();
+
+    let (oks, err) = iter.peek_oks(5);
+    assert_eq!(oks, vec![&1, &2]);
+    assert_eq!(err, None);
+}
+
+#[test]
+fn try_peek_surfaces_error_without_consuming() {
+    let results: [Result<i32, &str>; 3] = [Ok(1), Err("x"), Ok(3)];
+    let mut iter = results.into_iter().peekmore();
+
+    assert_eq!(iter.try_peek(), Ok(Some(&1)));
+
+    iter.advance_cursor();
+    assert_eq!(iter.try_peek(), Err("x"));
+    assert_eq!(iter.try_peek(), Err("x"));
+
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.next(), Some(Err("x")));
+
+    iter.advance_cursor();
+    assert_eq!(iter.try_peek(), Ok(None));
+}
+
+#[test]
+fn try_peek_with_question_mark() {
+    fn sum_first_two(results: &[Result<i32, &'static str>]) -> Result<i32, &'static str> {
+        let mut iter = results.iter().cloned().peekmore();
+        let first = *iter.try_peek()?.unwrap_or(&0);
+        iter.advance_cursor();
+        let second = *iter.try_peek()?.unwrap_or(&0);
+        Ok(first + second)
+    }
+
+    assert_eq!(sum_first_two(&[Ok(1), Ok(2), Err("x")]), Ok(3));
+    assert_eq!(sum_first_two(&[Ok(1), Err("x")]), Err("x"));
+    assert_eq!(sum_first_two(&[]), Ok(0));
+}